@@ -51,214 +51,3770 @@
 //! 
 //! const USERS2: [User; 1024] = const_arr!([User; 1024], |i| User { id: i as u32 });
 //! ```
+//! 
+//! Other macros:
+//! - [`make_static_arr`] - same as [`make_const_arr`], but creates `static` with single memory location.
+//! - [`const_arr_try`] - fallible init function returning `Result`.
+//! - [`const_arr_2d`] - 2D arrays from `|row, col|` init function.
+//! - [`const_arr_rev`] - fills array from the last index down to 0.
+//! - [`const_arr_cycle`] - repeats constant pattern.
+//! - [`const_map_arr`] and [`make_const_map_arr`] - map existing constant array.
+//! - [`const_zip_arr`] - zip two constant arrays.
+//! - [`const_arr_seeded`] - explicit value for index 0, for init functions invalid at 0.
+//! - [`const_arr_generic`] - `Copy` items, works with generic `TYPE` inside generic `const fn`.
+//! - [`const_arr_from_iter`] - init function takes integers from a range instead of index.
+//! - [`const_arr_with_len`] - init function also takes array length.
+//! - [`const_arr_none`] - array of `None` values.
+//! - [`make_const_table`] - array wrapped into single-field tuple struct.
+//! - [`const_arr_checked`] - integer array, values that don't fit in element type are compile error.
+//! - [`assert_const_arr_eq`] - compares constant arrays at compile time.
+//! - [`arr_from_fn`] - runtime version of [`const_arr`] for init functions that aren't `const`.
+//! - [`const_arr_strict`] - checks that init function depends on index.
+//! - [`const_arr_scan`] - passes accumulator from one item to the next.
+//! - [`const_arr_validated`] - checks finished array with predicate, returns `Result`.
+//! - [`const_arr_centered`] - init function takes signed index centered around zero.
+//! - [`make_const_arr_with_len`] - same as [`make_const_arr`], but also creates `const` with array length.
+//! - [`const_subarr`] - copies part of constant array.
+//! - [`const_arr_zeroed_seed`] - numeric array seeded with zeros instead of init function result for index 0.
+//! - [`make_const_arr_into`] - array passed to `const fn` constructor of another type.
+//! - [`const_concat_arr`] - concatenates two constant arrays.
+//! - [`const_arr_tiled`] - init function also gets index within repeating tile.
+//! - [`const_arr_debug_check`] - array is checked against init function in debug builds.
+//! - [`const_arr_offset`] - init function takes index starting from given offset.
+//! - [`const_interleave_arr`] - interleaves items of two constant arrays.
+//! - [`const_arr_ref`] - creates `&'static` reference to constant array.
+//! - [`const_arr_grid`] - creates flat array from `row` and `column` indices.
+//! - [`const_arr_until`] - init function returns `Option`, first `None` fills the rest with default.
+//! - [`const_arr_copy`] - numeric array filled with zeros, init function isn't called for index 0.
+//! - [`const_le_bytes_arr`] - creates array of little-endian bytes of integer.
+//! - [`const_be_bytes_arr`] - creates array of big-endian bytes of integer.
+//! - [`const_arr_try_verbose`] - same as [`const_arr_try`], but compile error shows failing index and error.
+//! - [`const_arr_like`] - array length is taken from other constant array.
+//! - [`make_const_arr_like`] - same as [`make_const_arr`], but array length is taken from other constant array.
+//! - [`const_arr_unzip`] - initializes two arrays from one init function returning a pair.
+//! - [`const_arr_recurrence`] - first items are given, the rest is computed from previous items.
+//! - [`make_const_arrs`] - same as [`make_const_arr`], but creates same array for multiple sizes.
+
+/// ### Macro used to initialize arrays in constant context
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - `CONST_INIT_FN` is const function or const-like closure from 
+///   `array index`(`usize`) to `TYPE`. Function can be specified by path,
+///   e.g. `Type::new` or `generic_fn::<3>`
+/// - `TYPE` is any type, including type aliases like `type Id = u32;`, macros expanding
+///   to type like `Int!()` and arrays of non-`Copy` items. For `|row, col|` init function
+///   see [`const_arr_2d`]
+/// - `SIZE` is any constant `usize` expression: literal (`256`, `0x100`, `1_024`, `256usize`),
+///   named `const`, const generic parameter or simple arithmetic like `N * 2`
+/// - Closure body is single expression, which may contain blocks anywhere inside,
+///   e.g. `|i| i as i32 + { let n = 2; n * n }`. Use block `|i| { ... }` for several statements.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const ARR1: [i32; 5] = const_arr!([i32; 5], |i| i as i32 + 1);
+/// assert_eq!(ARR1, [1, 2, 3, 4, 5]);
+/// 
+/// const fn to_i32_plus_one(n: usize) -> i32 {
+///     n as i32 + 1
+/// }
+/// 
+/// const ARR2: [i32; 5] = const_arr!([i32; 5], to_i32_plus_one);
+/// assert_eq!(ARR2, [1, 2, 3, 4, 5]);
+/// ```
+/// Extra constant arguments can be passed to init function after its name.
+/// Function is called as `INIT_FN(index, ARGS...)`:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const fn scaled(n: usize, factor: i32) -> i32 {
+///     n as i32 * factor
+/// }
+/// 
+/// const ARR: [i32; 5] = const_arr!([i32; 5], scaled, 10);
+/// assert_eq!(ARR, [0, 10, 20, 30, 40]);
+/// ```
+/// Function must be `const fn` item, not function pointer. Function pointers cannot be
+/// called in constant context, even if they point at `const fn`:
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const fn to_i32(n: usize) -> i32 {
+///     n as i32
+/// }
+/// 
+/// const TO_I32: fn(usize) -> i32 = to_i32;
+/// 
+/// const ARR: [i32; 5] = const_arr!([i32; 5], TO_I32);
+/// //                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ function pointer calls are not allowed in constants
+/// # let _ = ARR;
+/// ```
+/// 
+/// Array items can be function pointers, e.g. for dispatch tables. Init function
+/// selects them by index, usually with `match`, but cannot call them:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const fn add_one(x: u32) -> u32 { x + 1 }
+/// const fn double(x: u32) -> u32 { x * 2 }
+/// 
+/// const OPS: [fn(u32) -> u32; 3] = const_arr!([fn(u32) -> u32; 3], |i| match i {
+///     0 => add_one,
+///     _ => double,
+/// });
+/// assert_eq!(OPS[0](5), 6);
+/// assert_eq!(OPS[2](5), 10);
+/// ```
+/// 
+/// `char` items can be created from `u8` with `as` cast. Wider integers need
+/// `char::from_u32`, which is `const` only since Rust 1.67, so other chars can be
+/// taken from lookup table:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const LETTERS: [char; 5] = const_arr!([char; 5], |i| (b'a' + i as u8) as char);
+/// assert_eq!(LETTERS, ['a', 'b', 'c', 'd', 'e']);
+/// 
+/// const ALPHABET: [char; 4] = ['α', 'β', 'γ', 'δ'];
+/// 
+/// const GREEK: [char; 3] = const_arr!([char; 3], |i| ALPHABET[i + 1]);
+/// assert_eq!(GREEK, ['β', 'γ', 'δ']);
+/// ```
+/// 
+/// Associated `const fn` of a type can be used by its path, including generic types:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// struct Foo;
+/// 
+/// impl Foo {
+///     const fn gen(n: usize) -> u32 {
+///         n as u32 * 3
+///     }
+/// }
+/// 
+/// const ARR: [u32; 4] = const_arr!([u32; 4], Foo::gen);
+/// assert_eq!(ARR, [0, 3, 6, 9]);
+/// ```
+/// Trait methods cannot be `const fn` in stable Rust, so qualified path like
+/// `<Foo as Bar>::gen` is a compile error. Calling trait method from inherent `const fn` isn't
+/// possible either - move function to inherent `impl` block or make it free `const fn`.
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// struct Foo;
+/// 
+/// trait Bar {
+///     fn gen(n: usize) -> u32;
+/// }
+/// 
+/// impl Bar for Foo {
+///     fn gen(n: usize) -> u32 {
+///         n as u32
+///     }
+/// }
+/// 
+/// const ARR: [u32; 4] = const_arr!([u32; 4], <Foo as Bar>::gen);
+/// //                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Trait methods can't be used as init function
+/// # let _ = ARR;
+/// ```
+/// 
+/// Init function can be defined in place as `fn(i) { BODY }`, which is the same
+/// as closure `|i| { BODY }`:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 3] = const_arr!([i32; 3], fn(i) {
+///     let n = i as i32;
+///     n * n
+/// });
+/// assert_eq!(ARR, [0, 1, 4]);
+/// ```
+/// 
+/// Closure can also take second argument - read-only slice of the array
+/// filled so far. It is useful for cumulative tables:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const SUMS: [i32; 5] = const_arr!([i32; 5], |i, arr| {
+///     if i == 0 { 1 } else { arr[i - 1] + i as i32 + 1 }
+/// });
+/// assert_eq!(SUMS, [1, 3, 6, 10, 15]);
+/// ```
+/// - `arr[j]` for `j < i` is already computed.
+/// - `arr[j]` for `j >= i` still holds the seed value, which is body evaluated at index 0.
+/// - At index 0 `arr` is an empty slice, because there is no array yet.
+/// - References in `TYPE` need explicit `'static` lifetime, e.g. `[&'static str; N]`,
+///   because `arr` is a reference itself.
+/// 
+/// Closure with more parameters is a compile error:
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 3] = const_arr!([i32; 3], |i, arr, j| i as i32);
+/// //                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Init function takes 1 or 2 parameters
+/// # let _ = ARR;
+/// ```
+/// 
+/// Index type can be specified if `usize` isn't convenient. Index that doesn't
+/// fit in this type is a compile error.
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const fn reverse_bits(b: u8) -> u8 {
+///     b.reverse_bits()
+/// }
+/// 
+/// const REV: [u8; 256] = const_arr!([u8; 256], |b: u8| reverse_bits(b));
+/// assert_eq!(REV[1], 0x80);
+/// ```
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [u8; 257] = const_arr!([u8; 257], |b: u8| b);
+/// # let _ = ARR;
+/// ```
+/// 
+/// Index can be wrapped in tuple struct with single `usize` field and destructured
+/// in closure parameter, e.g. `|Idx(i)|`:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// struct Idx(usize);
+/// 
+/// const ARR: [u32; 4] = const_arr!([u32; 4], |Idx(i)| i as u32 * 2);
+/// assert_eq!(ARR, [0, 2, 4, 6]);
+/// ```
+/// - Wrapper is written as single identifier, so it must be in scope.
+/// - Pattern inside of wrapper must be irrefutable, e.g. `i`, `_` or `mut i`.
+/// - Wrapper cannot implement `Drop`, because it is destructured in constant context.
+/// 
+/// Closure can specify return type. In this case `TYPE` can be replaced with `_`:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [u64; 3] = const_arr!([_; 3], |i| -> u64 { 1 << (i * 8) });
+/// assert_eq!(ARR, [1, 256, 65536]);
+/// ```
+/// 
+/// Array can be filled with single constant value, like `[VALUE; SIZE]` but
+/// `VALUE` doesn't have to be `Copy`:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [u8; 4] = const_arr!([u8; 4]; 0xFF);
+/// assert_eq!(ARR, [0xFF, 0xFF, 0xFF, 0xFF]);
+/// ```
+/// Closure without parameters does the same:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 3] = const_arr!([i32; 3], || 7);
+/// assert_eq!(ARR, [7, 7, 7]);
+/// ```
+/// 
+/// `VALUE` is evaluated once, so it can be call to `const fn` without arguments.
+/// Function name alone is treated as init function from index, so it must be called:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const fn default_name() -> &'static str {
+///     "unknown"
+/// }
+/// 
+/// const ARR: [&str; 3] = const_arr!([&str; 3]; default_name());
+/// assert_eq!(ARR, ["unknown", "unknown", "unknown"]);
+/// ```
+/// ```compile_fail,E0061
+/// use const_array_init::const_arr;
+/// 
+/// const fn default_name() -> &'static str {
+///     "unknown"
+/// }
+/// 
+/// const ARR: [&str; 3] = const_arr!([&str; 3], default_name);
+/// //                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ this function takes 0 arguments but 1 argument was supplied
+/// # let _ = ARR;
+/// ```
+/// 
+/// Closure body is inserted into the expansion as is, so if it panics or indexes
+/// out of bounds during evaluation, compiler points at the body itself:
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr;
+/// 
+/// const SRC: [i32; 2] = [1, 2];
+/// 
+/// const ARR: [i32; 3] = const_arr!([i32; 3], |i| SRC[i]);
+/// //                                             ^^^^^^ index out of bounds: the length is 2 but the index is 2
+/// # let _ = ARR;
+/// ```
+/// 
+/// Closure body is evaluated in constant context, so it can reference only constant
+/// values from outside:
+/// - `const` items, including ones declared in enclosing block, and associated consts
+/// - `const fn`s
+/// 
+/// It cannot reference `let` bindings or function arguments, even inside of `const` block,
+/// and generic parameters of enclosing function (see [`const_arr_generic`] for that).
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const THRESHOLD: i32 = 10;
+/// 
+/// const ARR: [i32; 3] = {
+///     const STEP: i32 = 2;
+///     const_arr!([i32; 3], |i| i as i32 * STEP + THRESHOLD)
+/// };
+/// assert_eq!(ARR, [10, 12, 14]);
+/// ```
+/// Compiler reports `let` binding as non-constant value. Suggestion to use `let` instead
+/// of `const` points into macro internals and should be ignored - make binding `const` instead.
+/// ```compile_fail,E0435
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 3] = {
+///     let step = 2;
+///     const_arr!([i32; 3], |i| i as i32 * step)
+/// //                                      ^^^^ non-constant value
+/// };
+/// # let _ = ARR;
+/// ```
+/// 
+/// Array is filled by `while` loop in constant context. Before Rust 1.72 constant evaluation
+/// has step limit, and arrays over about `150_000` items with simple init function fail with
+/// `exceeded interpreter step limit`. Since Rust 1.72 compiler reports lint
+/// `constant evaluation is taking a long time` for arrays over about `1_000_000` items instead,
+/// which can be allowed for the item. Big arrays should be `static`, so they aren't copied
+/// into every place they are used:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// // Lint is unknown before Rust 1.72
+/// #[allow(unknown_lints, long_running_const_eval)]
+/// static ARR: [u8; 4096] = const_arr!([u8; 4096], |i| i as u8);
+/// assert_eq!(ARR[4095], 0xFF);
+/// ```
+/// 
+/// Closure body is inserted into expansion twice: into seed `const` for index 0 and
+/// into the loop for other indices. Body is still evaluated once for every index,
+/// and comments or `#[cfg]` attributes on its statements work the same in both places.
+/// 
+/// Init function is evaluated for index 0 first, and this value is used to fill the
+/// array before every other index is computed. So init function must be valid for index 0:
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const INV: [usize; 4] = const_arr!([usize; 4], |i| 100 / i);
+/// //                                                  ^^^^^^^ attempt to divide `100_usize` by zero
+/// # let _ = INV;
+/// ```
+/// Otherwise value for index 0 can be passed as `seed = SEED` (same as [`const_arr_seeded`]).
+/// Init function is then called only for other indices:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const INV: [usize; 4] = const_arr!([usize; 4], seed = 0, |i| 100 / i);
+/// assert_eq!(INV, [0, 100, 50, 33]);
+/// ```
+/// 
+/// This is useful for types without valid value at index 0, e.g. `NonZeroU32` from index:
+/// ```
+/// use const_array_init::const_arr;
+/// use core::num::NonZeroU32;
+/// 
+/// const fn non_zero(n: usize) -> NonZeroU32 {
+///     match NonZeroU32::new(n as u32) {
+///         Some(n) => n,
+///         None => panic!("index is 0"),
+///     }
+/// }
+/// 
+/// const ARR: [NonZeroU32; 3] = const_arr!([NonZeroU32; 3], seed = non_zero(100), non_zero);
+/// assert_eq!(ARR[0].get(), 100);
+/// assert_eq!(ARR[2].get(), 2);
+/// ```
+/// 
+/// Any type with `const` constructor can be used, e.g. `Duration`. Types without one, like
+/// `std::time::Instant`, cannot be created in constant context at all.
+/// ```
+/// use const_array_init::const_arr;
+/// use core::time::Duration;
+/// 
+/// const TIMEOUTS: [Duration; 4] = const_arr!([Duration; 4], |i| Duration::from_secs(1 << i));
+/// assert_eq!(TIMEOUTS[3], Duration::from_secs(8));
+/// ```
+/// 
+/// Item can be array itself, so rows of 2D array can be created whole by `const fn`
+/// (see [`const_arr_2d`] to create them item by item):
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const COLS: usize = 4;
+/// 
+/// const fn build_row(row: usize) -> [u8; COLS] {
+///     [row as u8; COLS]
+/// }
+/// 
+/// const ROWS: [[u8; COLS]; 3] = const_arr!([[u8; COLS]; 3], build_row);
+/// assert_eq!(ROWS, [[0; 4], [1; 4], [2; 4]]);
+/// ```
+/// 
+/// Items can be slices of one constant pool. Range indexing `&POOL[START..END]` isn't
+/// allowed in constant context and `split_at` is `const` only since Rust 1.71,
+/// so slice can be cut with slice patterns:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const POOL: [u8; 6] = *b"keyval";
+/// const OFFSETS: [usize; 3] = [0, 3, 6];
+/// 
+/// const fn sub(pool: &'static [u8], start: usize, end: usize) -> &'static [u8] {
+///     let mut slice = pool;
+///     while slice.len() > pool.len() - start {
+///         if let [_, rest @ ..] = slice { slice = rest; }
+///     }
+///     while slice.len() > end - start {
+///         if let [rest @ .., _] = slice { slice = rest; }
+///     }
+///     slice
+/// }
+/// 
+/// const WORDS: [&[u8]; 2] = const_arr!([&[u8]; 2], |i| sub(&POOL, OFFSETS[i], OFFSETS[i + 1]));
+/// assert_eq!(WORDS, [b"key", b"val"]);
+/// ```
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const POOL: [u8; 6] = *b"keyval";
+/// 
+/// const WORDS: [&[u8]; 2] = const_arr!([&[u8]; 2], |i| &POOL[i * 3..i * 3 + 3]);
+/// //                                                    ^^^^^^^^^^^^^^^^^^^^^^^^ `Index` trait can't be called in const fn
+/// # let _ = WORDS;
+/// ```
+/// 
+/// Init function isn't evaluated at all if array size is literal `0`. Named constant
+/// equal to 0 cannot be detected by macro, so init function is still evaluated for index 0.
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const INV: [usize; 0] = const_arr!([usize; 0], |i| 100 / i);
+/// assert_eq!(INV, []);
+/// ```
+/// 
+/// You have to specify array type in const context, even if compiler can infer it.
+/// Macro expands to block with fully specified type, so compiler suggests exact
+/// annotation, but `const` and `static` items require it anyway.
+/// 
+/// This is good `quick-fix` opportunity for your language server.
+/// 
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const ARR = const_arr!([i32; 5], |i| i as i32);
+/// //       ^ help: provide a type for the constant: `: [i32; 5]`
+/// # let _ = ARR;
+/// ```
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 5] = const_arr!([i32; 5], |i| i as i32);
+/// # let _ = ARR;
+/// ```
+/// 
+/// `let` bindings don't need annotation:
+/// ```
+/// use const_array_init::const_arr;
+/// 
+/// let arr = const_arr!([i32; 5], |i| i as i32);
+/// assert_eq!(arr, [0, 1, 2, 3, 4]);
+/// ```
+/// 
+/// `let` binding is computed at runtime, unless macro is wrapped into inline `const` block.
+/// Unlike `const` item, inline `const` block in generic function can use const generic
+/// parameter as `SIZE` (but not in init function). Inline `const` requires Rust 1.79:
+/// ```ignore
+/// use const_array_init::const_arr;
+/// 
+/// fn doubled<const N: usize>() -> [usize; N] {
+///     const { const_arr!([usize; N], |i| i * 2) }
+/// }
+/// 
+/// let arr = const { const_arr!([i32; 5], |i| i as i32) };
+/// assert_eq!(arr, [0, 1, 2, 3, 4]);
+/// assert_eq!(doubled::<3>(), [0, 2, 4]);
+/// ```
+/// 
+/// Sizes in type annotation and in macro call must be the same. Macro cannot see the
+/// annotation, but compiler points at the macro call and names both sizes:
+/// ```compile_fail,E0308
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 5] = const_arr!([i32; 4], |i| i as i32);
+/// //               -    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ expected an array with a size of 5, found one with a size of 4
+/// //               |
+/// //               help: consider specifying the actual array length: `4`
+/// # let _ = ARR;
+/// ```
+/// 
+/// Float or string literal `SIZE` is rejected with targeted error. It is reported as
+/// const evaluation error, because macro cannot tell literals of different types apart:
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 3] = const_arr!([i32; 3.0], |i| i as i32);
+/// //                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ const_arr!: array size SIZE must be usize literal, found float literal
+/// # let _ = ARR;
+/// ```
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 3] = const_arr!([i32; "3"], |i| i as i32);
+/// //                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ const_arr!: array size SIZE must be usize literal, found string literal
+/// # let _ = ARR;
+/// ```
+/// 
+/// Negative `SIZE` literal is rejected by macro with targeted error:
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 1] = const_arr!([i32; -1], |i| i as i32);
+/// // error: Array size SIZE cannot be negative. It should be usize:
+/// # let _ = ARR;
+/// ```
+/// 
+/// `SIZE` expression which overflows `usize` is evaluated by compiler before anything
+/// in expansion, so it is reported at `SIZE` itself:
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 1] = const_arr!([i32; usize::MAX + 1], |i| i as i32);
+/// //                                     ^^^^^^^^^^^^^^ attempt to compute `usize::MAX + 1_usize`, which would overflow
+/// # let _ = ARR;
+/// ```
+/// 
+/// Arguments in wrong format are echoed back in error message:
+/// ```compile_fail
+/// use const_array_init::const_arr;
+/// 
+/// const ARR: [i32; 5] = const_arr!(i32, 5, |i| i as i32);
+/// // error: Wrong format. It should be [TYPE; SIZE], INIT_FN:
+/// //              const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);
+/// //         e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);
+/// //         got:  const_arr!(i32, 5, |i| i as i32)
+/// # let _ = ARR;
+/// ```
+/// 
+/// But if you don't want to specify type twice you can use 
+/// - `make_const_arr!(NAME, [TYPE; SIZE], INIT_FN)` macro.
+/// 
+/// ```
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR, [i32; 5], |i| i as i32 + 1);
+/// 
+/// assert_eq!(ARR, [1, 2, 3, 4, 5]);
+/// ```
+/// 
+/// - See [`make_const_arr`]
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr {
+    // `fn` cannot be parsed as path, so this arm goes before arms with init function path
+    ([$TYPE:ty; $($SIZE:tt)+], fn($name:ident) $body:block $(,)?) => {
+        $crate::const_arr!([$TYPE; $($SIZE)+], |$name| $body)
+    };
+    ([$type:ty; - $($size:tt)+] $($args:tt)*) => {compile_error!(concat!("Array size SIZE cannot be negative. It should be usize: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!([$type; - $($size)+] $($args)*), ")")); };
+    // Array of literal size 0 needs no elements, so init function is never evaluated.
+    // It is still type-checked in dead branch, so functions it uses aren't reported as unused.
+    ([_; 0], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $crate::const_arr!([$RET; 0], |$name| -> $RET $body)
+    };
+    ([$TYPE:ty; 0], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $crate::const_arr!([$TYPE; 0], |$name| {
+            let item: $RET = $body;
+            item
+        })
+    };
+    ([$TYPE:ty; 0], $func_path:path $(,)?) => {
+        {
+            if false {
+                #[allow(unreachable_code)]
+                ::core::mem::forget::<$TYPE>($func_path(0));
+            }
+            let arr: [$TYPE; 0] = [];
+            arr
+        }
+    };
+    ([$TYPE:ty; 0], $func_path:path, $($extra:expr),+ $(,)?) => {
+        $crate::const_arr!([$TYPE; 0], |ind| $func_path(ind, $($extra),+))
+    };
+    ([$TYPE:ty; 0], |$name:ident| $body:expr $(,)?) => {
+        {
+            if false {
+                #[allow(unused_variables)]
+                let $name: usize = 0;
+                #[allow(unreachable_code)]
+                ::core::mem::forget::<$TYPE>($body);
+            }
+            let arr: [$TYPE; 0] = [];
+            arr
+        }
+    };
+    ([$TYPE:ty; 0], |$name:ident, $arr:ident| $body:expr $(,)?) => {
+        {
+            if false {
+                #[allow(unused_variables)]
+                let $name: usize = 0;
+                #[allow(unused_variables)]
+                let $arr: &[$TYPE] = &[];
+                #[allow(unreachable_code)]
+                ::core::mem::forget::<$TYPE>($body);
+            }
+            let arr: [$TYPE; 0] = [];
+            arr
+        }
+    };
+    ([$TYPE:ty; 0], |$name:ident: $INDEX:ty| $body:expr $(,)?) => {
+        {
+            if false {
+                #[allow(unused_variables)]
+                let $name: $INDEX = 0;
+                #[allow(unreachable_code)]
+                ::core::mem::forget::<$TYPE>($body);
+            }
+            let arr: [$TYPE; 0] = [];
+            arr
+        }
+    };
+    ([$TYPE:ty; 0], |_| $body:expr $(,)?) => {
+        {
+            if false {
+                #[allow(unreachable_code)]
+                ::core::mem::forget::<$TYPE>($body);
+            }
+            let arr: [$TYPE; 0] = [];
+            arr
+        }
+    };
+    ([$TYPE:ty; 0], |$Wrap:ident($inner:pat)| $body:expr $(,)?) => {
+        {
+            if false {
+                #[allow(unused_variables)]
+                let $Wrap($inner) = $Wrap(0);
+                #[allow(unreachable_code)]
+                ::core::mem::forget::<$TYPE>($body);
+            }
+            let arr: [$TYPE; 0] = [];
+            arr
+        }
+    };
+    ([$TYPE:ty; 0]; $VALUE:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; 0], |_| $VALUE)
+    };
+    ([$TYPE:ty; 0], || $body:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; 0], |_| $body)
+    };
+    ([_; $SIZE:expr], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $crate::const_arr!([$RET; $SIZE], |$name| -> $RET $body)
+    };
+    // Literal SIZE is converted to `usize` constant first, so float or string literal
+    // is reported with targeted message instead of type mismatch inside of expansion.
+    ([$type:ty; $size:expr], ||) => {compile_error!(concat!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!([$type; $size], ||), ")")); };
+    ([$TYPE:ty; $SIZE:literal], $($init:tt)+) => {
+        {
+            const __SIZE: usize = $crate::__ArraySize($SIZE).get();
+            $crate::const_arr!([$TYPE; __SIZE], $($init)+)
+        }
+    };
+    ([$TYPE:ty; $SIZE:literal]; $($init:tt)+) => {
+        {
+            const __SIZE: usize = $crate::__ArraySize($SIZE).get();
+            $crate::const_arr!([$TYPE; __SIZE]; $($init)+)
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE], |$name| {
+            let item: $RET = $body;
+            item
+        })
+    };
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(, $extra:expr)* $(,)?) => {
+        {
+            // Array is filled by inner const fn generic over size, so expansion at
+            // call site is single function call, and SIZE can still be generic
+            // parameter of enclosing item. Unused `&'static ()` makes elided lifetimes
+            // in $TYPE 'static, same as in const items.
+            #[allow(unknown_lints, mismatched_lifetime_syntaxes)]
+            const fn __fill<const N: usize>(_: &'static ()) -> [$TYPE; N] {
+                // Create array of proper SIZE and initialize it with garbage data 
+                // using $func_path(0) call as if every value had index 0.
+                // 
+                // There is no way to create array without initializing it and
+                // we cannot initialize it with 0-s because it isn't always valid (e.g. references)
+                // and MaybeUninit is unsafe and unstable in const context.
+                const __TEMP_ITEM: $TYPE = $func_path(0 $(, $extra)*);
+                let mut arr: [$TYPE; N] = [__TEMP_ITEM; N];
+
+                // Initialize array with proper data using $func_path(ind) call.
+                // Index 0 already holds $func_path(0) from __TEMP_ITEM, so start from 1.
+                let mut ind = 1;
+                while ind < N {
+                    arr[ind] = $func_path(ind $(, $extra)*);
+                    ind += 1;
+                }
+                arr
+            }
+            #[allow(unused_braces)]
+            let arr: [$TYPE; $SIZE] = __fill::<{ $SIZE }>(&());
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            // Create array of proper SIZE and initialize it with garbage data 
+            // using $body with $name predefined to 0 as if every value had index 0.
+            // 
+            // There is no way to create array without initializing it and
+            // we cannot initialize it with 0-s because it isn't always valid (e.g. references)
+            // and MaybeUninit is is unsafe and unstable in const context.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: usize = 0;
+                    $body
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            // Initialize array with proper data from closure's body.
+            // Index 0 already holds $body evaluated at 0 from __TEMP_ITEM, so start from 1.
+            // Length is read once, because `len()` call on every iteration adds
+            // const evaluation steps, which are limited for big arrays.
+            let len = arr.len();
+            let mut $name = 1;
+            while $name < len {
+                arr[$name] = $body;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident, $arr:ident| $body:expr $(,)?) => {
+        {
+            // Same as closure with single argument, but $arr is bound to
+            // read-only slice view of the array. There is no array yet when
+            // __TEMP_ITEM is computed, so $arr is empty slice at index 0.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: usize = 0;
+                    #[allow(unused_variables)]
+                    let $arr: &[$TYPE] = &[];
+                    $body
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            let len = arr.len();
+            let mut $name = 1;
+            while $name < len {
+                let item = {
+                    let $arr: &[$TYPE] = &arr;
+                    $body
+                };
+                arr[$name] = item;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident: $INDEX:ty| $body:expr $(,)?) => {
+        {
+            // Same as closure without index type, but $name has type $INDEX.
+            // Loop counter is still `usize` and is cast to $INDEX for every item.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: $INDEX = 0;
+                    $body
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            let len = arr.len();
+            let mut ind: usize = 1;
+            while ind < len {
+                let $name = ind as $INDEX;
+                // Cast back to check that index fits in $INDEX
+                if $name as usize != ind {
+                    panic!("const_arr!: array index doesn't fit in closure index type");
+                }
+                arr[ind] = $body;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], |$Wrap:ident($inner:pat)| $body:expr $(,)?) => {
+        {
+            // Index is wrapped in single-field tuple struct $Wrap and destructured,
+            // so body sees only bindings of $inner pattern.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $Wrap($inner) = $Wrap(0);
+                    $body
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            let len = arr.len();
+            let mut ind = 1;
+            while ind < len {
+                let item = {
+                    let $Wrap($inner) = $Wrap(ind);
+                    $body
+                };
+                arr[ind] = item;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], |_| $body:expr $(,)?) => {
+        {
+            const __TEMP_ITEM: $TYPE = $body;
+            [__TEMP_ITEM; $SIZE]
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr]; $VALUE:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE], |_| $VALUE)
+    };
+    ([$TYPE:ty; $SIZE:expr], seed = $SEED:expr, $($init:tt)+) => {
+        $crate::const_arr_seeded!([$TYPE; $SIZE], $SEED, $($init)+)
+    };
+    ([$TYPE:ty; $SIZE:expr], || $body:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE], |_| $body)
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr]) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], ) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], |$($_p:tt),+| $($_b:tt)+) => {compile_error!("Init function takes 1 or 2 parameters: |i| INDEX_BODY or |i, arr| ARRAY_SO_FAR_BODY: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], $num:literal) => {compile_error!("Please add |_| to last argument to turn it to closure or use ; to repeat value: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ]; 0);"); };
+    ([$type:ty; $size:expr], <$($_q:tt)+) => {compile_error!("Trait methods can't be used as init function, because they can't be const fn. Use inherent associated const fn instead: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], Foo::gen);"); };
+    ($type:ty) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!($type), ")")); };
+    ($type:ty, ) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!($type, ), ")")); };
+    ($type:ty,$size:expr) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!($type,$size), ")")); };
+    ($type:ty,$size:expr, $_:tt) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!($type,$size, $_), ")")); };
+    ($type:ty,$size:expr, |$_:tt| $_n2:tt) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!($type,$size, |$_| $_n2), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be [TYPE; SIZE], INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);\n got:  const_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// Literal array size of [`const_arr`] checked in constant context.
+/// Not part of public API.
+#[doc(hidden)]
+pub struct __ArraySize<T>(pub T);
+
+impl __ArraySize<usize> {
+    pub const fn get(self) -> usize {
+        self.0
+    }
+}
+
+impl __ArraySize<f64> {
+    pub const fn get(self) -> usize {
+        panic!("const_arr!: array size SIZE must be usize literal, found float literal")
+    }
+}
+
+impl __ArraySize<&str> {
+    pub const fn get(self) -> usize {
+        panic!("const_arr!: array size SIZE must be usize literal, found string literal")
+    }
+}
+
+/// ### Wrapper around [`const_arr`] macro. Allows to specify the type of an array `single` time.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// make_const_arr!(ARR_NAME, [TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const ARR_NAME: [TYPE; SIZE] = const_arr!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - `CONST_INIT_FN` is const function or const-like closure from 
+///   `array index`(`usize`) to `TYPE`. Function can be specified by path,
+///   e.g. `Type::new` or `generic_fn::<3>`
+/// - `SIZE` is any constant `usize` expression: literal, named `const`,
+///   const generic parameter or simple arithmetic like `N * 2`
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR1, [i32; 5], |i| i as i32 + 1);
+/// assert_eq!(ARR1, [1, 2, 3, 4, 5]);
+/// 
+/// const fn to_i32_plus_one(n: usize) -> i32 {
+///     n as i32 + 1
+/// }
+/// 
+/// make_const_arr!(ARR2, [i32; 5], to_i32_plus_one);
+/// assert_eq!(ARR2, [1, 2, 3, 4, 5]);
+/// ```
+/// 
+/// `SIZE` is written once, so unlike with [`const_arr`] it cannot get out of sync
+/// with `const` type annotation. Array is still checked where it is used:
+/// ```compile_fail,E0308
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR, [i32; 4], |i| i as i32);
+/// 
+/// const COPY: [i32; 5] = ARR;
+/// //                -    ^^^ expected an array with a size of 5, found one with a size of 4
+/// # let _ = COPY;
+/// ```
+/// 
+/// Visibility can be specified before `ARR_NAME`:
+/// ```
+/// mod tables {
+///     use const_array_init::make_const_arr;
+/// 
+///     make_const_arr!(pub ARR, [i32; 5], |i| i as i32 + 1);
+/// }
+/// 
+/// assert_eq!(tables::ARR, [1, 2, 3, 4, 5]);
+/// ```
+/// 
+/// If closure specifies return type, `TYPE` can be replaced with `_`:
+/// ```
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR1, [_; 3], |i| -> u64 { 1 << (i * 8) });
+/// assert_eq!(ARR1, [1, 256, 65536]);
+/// 
+/// make_const_arr!(ARR2, _, 3, |i| -> u64 { 1 << (i * 8) });
+/// assert_eq!(ARR2, [1, 256, 65536]);
+/// ```
+/// 
+/// Init function takes 1 or 2 parameters, same as in [`const_arr`]:
+/// ```compile_fail
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR, [i32; 3], |i, arr, j| i as i32);
+/// ```
+/// 
+/// `const` must have explicit type, so it cannot be inferred from closure body alone:
+/// ```compile_fail
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR, _, 3, |i| 1_u64 << (i * 8));
+/// ```
+/// 
+/// Attributes and doc comments are applied to created `const`:
+/// ```
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(
+///     /// Squares of first 5 natural numbers
+///     #[allow(dead_code)]
+///     SQUARES, [u32; 5], |i| (i * i) as u32
+/// );
+/// ```
+/// 
+/// Name and type can also be written like in `const` declaration:
+/// ```
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR: [i32; 5] = |i| i as i32 + 1);
+/// assert_eq!(ARR, [1, 2, 3, 4, 5]);
+/// ```
+/// 
+/// Macro can be used inside of `impl` block to create associated `const`. Name can be
+/// written as `Self::NAME` to make it clear. Size can be generic parameter of `impl`,
+/// but init function cannot use generic parameters or `Self` (see [`const_arr_generic`]):
+/// ```
+/// use const_array_init::make_const_arr;
+/// 
+/// struct Table<const N: usize>;
+/// 
+/// impl<const N: usize> Table<N> {
+///     make_const_arr!(pub Self::SQUARES, [u32; N], |i| (i * i) as u32);
+/// }
+/// 
+/// assert_eq!(Table::<4>::SQUARES, [0, 1, 4, 9]);
+/// ```
+/// 
+/// Size and type can be written without brackets as `SIZE, TYPE`. `SIZE` must be
+/// literal, path to `const` (e.g. `Color::COUNT`) or expression in parentheses:
+/// ```
+/// use const_array_init::make_const_arr;
+/// 
+/// const fn to_u8(n: usize) -> u8 {
+///     n as u8
+/// }
+/// 
+/// make_const_arr!(ARR1, 256, u8, |i| i as u8);
+/// make_const_arr!(ARR2, 256, u8, to_u8);
+/// make_const_arr!(ARR3, 256, u8; 0xFF);
+/// assert_eq!(ARR1, ARR2);
+/// assert_eq!(ARR3[255], 0xFF);
+/// ```
+/// 
+/// Arguments in wrong format are echoed back in error message:
+/// ```compile_fail
+/// use const_array_init::make_const_arr;
+/// 
+/// make_const_arr!(ARR, (i32, 5), |i| i as i32);
+/// // error: Wrong format. It should be ARR_NAME, [TYPE; SIZE], INIT_FN:
+/// //              make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);
+/// //         e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);
+/// //         got:  make_const_arr!(ARR, (i32, 5), |i| i as i32)
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_const_arr {
+    ($(#[$attr:meta])* $vis:vis Self::$NAME:ident, $($rest:tt)+) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, $($rest)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident: [$($arr:tt)+] = $($init:tt)+) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$($arr)+], $($init)+);
+    };
+    // `fn` cannot be parsed as path, so this arm goes before arms with init function path
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $($SIZE:tt)+], fn($name:ident) $body:block $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $($SIZE)+] = $crate::const_arr!([$TYPE; $($SIZE)+], |$name| $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty; - $($size:tt)+] $($args:tt)*) => { compile_error!(concat!("Array size SIZE cannot be negative. It should be usize: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_const_arr!(", stringify!($(#[$attr])* $vis $NAME, [$type; - $($size)+] $($args)*), ")")); };
+    // Size is passed to const_arr! as literal, so it can skip init function for 0
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [_; 0], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$RET; 0] = $crate::const_arr!([$RET; 0], |$name| -> $RET $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; 0] $($init:tt)+) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; 0] = $crate::const_arr!([$TYPE; 0] $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [_; $SIZE:expr], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$RET; $SIZE] = $crate::const_arr!([$RET; $SIZE], |$name| -> $RET $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |$name| -> $RET $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], $func_path);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], $func_path:path, $($extra:expr),+ $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], $func_path, $($extra),+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |$name| $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], |$name:ident, $arr:ident| $body:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |$name, $arr| $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], |$name:ident: $INDEX:ty| $body:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |$name: $INDEX| $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], |_| $body:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |_| $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], || $body:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], || $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], seed = $SEED:expr, $($init:tt)+) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], seed = $SEED, $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], |$Wrap:ident($inner:pat)| $body:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |$Wrap($inner)| $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr]; $VALUE:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE]; $VALUE);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, _, $SIZE:expr, |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [_; $SIZE], |$name| -> $RET $body);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, _, $SIZE:expr, $($_:tt)*) => { compile_error!("Type of const cannot be inferred from closure body. Please specify closure return type: \n      make_const_arr!(ARR_NAME, _, SIZE, |i| -> TYPE { BODY });\n e.g. make_const_arr!(MY_ARR  , _, 1024, |i| -> i32 { i as i32 });"); };
+    // Parentheses around SIZE are removed, so they aren't reported as unused
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, ($SIZE:expr), $TYPE:ty, $($init:tt)+) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $SIZE], $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, ($SIZE:expr), $TYPE:ty; $VALUE:expr $(,)?) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $SIZE]; $VALUE);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $SIZE:path, $TYPE:ty, $($init:tt)+) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $SIZE], $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $SIZE:path, $TYPE:ty; $VALUE:expr $(,)?) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $SIZE]; $VALUE);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $SIZE:tt, $TYPE:ty, $($init:tt)+) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $SIZE], $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $SIZE:tt, $TYPE:ty; $VALUE:expr $(,)?) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $SIZE]; $VALUE);
+    };
+    () => { compile_error!("Please specify array name ARR_NAME: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($_:literal) => { compile_error!("Please specify array name ARR_NAME: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident) => { compile_error!("Please specify array type TYPE: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, ) => { $crate::make_const_arr!($(#[$attr])* $vis $NAME); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty]) => { compile_error!("Please add SIZE to array type: It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty;]) => { compile_error!("Please add SIZE to array type: It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty;$size:expr]) => { compile_error!("Please specify init function INIT_FN: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty;$size:expr], |$($_p:tt),+| $($_b:tt)+) => { compile_error!("Init function takes 1 or 2 parameters: |i| INDEX_BODY or |i, arr| ARRAY_SO_FAR_BODY: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty;$size:expr], $num:literal) => { compile_error!("Please add |_| to last argument to turn it to closure or use ; to repeat value: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024]; 0);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $_:tt) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_const_arr!(", stringify!($(#[$attr])* $vis $NAME, $_), ")")); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $_n1:tt, $_n2:tt) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_const_arr!(", stringify!($(#[$attr])* $vis $NAME, $_n1, $_n2), ")")); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $_n1:tt, $_n2:tt, $_n3:tt) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_const_arr!(", stringify!($(#[$attr])* $vis $NAME, $_n1, $_n2, $_n3), ")")); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $_n1:tt, $_n2:tt, $_fn_name:ident) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_const_arr!(", stringify!($(#[$attr])* $vis $NAME, $_n1, $_n2, $_fn_name), ")")); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $_n1:tt, $_n2:tt, |$_cl:tt| $_b:tt) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_const_arr!(", stringify!($(#[$attr])* $vis $NAME, $_n1, $_n2, |$_cl| $_b), ")")); };
+    ($($args:tt)*) => { compile_error!(concat!("Wrong format. It should be ARR_NAME, [TYPE; SIZE], INIT_FN: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_const_arr!(", stringify!($($args)*), ")")); };
+}
+/// ### Same as [`make_const_arr`], but creates `static` instead of `const`.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// `const` is copied into every place it is used, while `static` has single
+/// location in memory. Prefer this macro for big lookup tables.
+/// 
+/// Usage:
+/// ```ignore
+/// make_static_arr!(ARR_NAME, [TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// static ARR_NAME: [TYPE; SIZE] = const_arr!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_static_arr;
+/// 
+/// make_static_arr!(ARR1, [i32; 5], |i| i as i32 + 1);
+/// assert_eq!(ARR1, [1, 2, 3, 4, 5]);
+/// 
+/// const fn to_i32_plus_one(n: usize) -> i32 {
+///     n as i32 + 1
+/// }
+/// 
+/// make_static_arr!(ARR2, [i32; 5], to_i32_plus_one);
+/// assert_eq!(ARR2, [1, 2, 3, 4, 5]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_static_arr {
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; 0] $($init:tt)+) => {
+        $(#[$attr])*
+        $vis static $NAME: [$TYPE; 0] = $crate::const_arr!([$TYPE; 0] $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], $($init:tt)+) => {
+        $(#[$attr])*
+        $vis static $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr]; $VALUE:expr $(,)?) => {
+        $(#[$attr])*
+        $vis static $NAME: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE]; $VALUE);
+    };
+    () => { compile_error!("Please specify array name ARR_NAME: \n      make_static_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_static_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident $(,)?) => { compile_error!("Please specify array type TYPE: \n      make_static_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_static_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty; $size:expr] $(,)?) => { compile_error!("Please specify init function INIT_FN: \n      make_static_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_static_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
+    ($($args:tt)*) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_static_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_static_arr!(MY_ARR  , [i32;  1024], |i| i as i32);\n got:  make_static_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but creates `&'static` reference to array.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const REF: &[TYPE; SIZE] = const_arr_ref!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const REF: &[TYPE; SIZE] = {
+///     const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], CONST_INIT_FN);
+///     &ARR
+/// };
+/// ```
+/// 
+/// - Array is built in `const` item, so reference to it is promoted to `'static`
+/// - `SIZE` cannot use generic parameters, because they aren't available in `const` item
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_ref;
+/// 
+/// const BYTES: &[u8; 4] = const_arr_ref!([u8; 4], |i| i as u8);
+/// assert_eq!(BYTES, &[0, 1, 2, 3]);
+/// 
+/// // Reference to array coerces to slice
+/// const SLICE: &[u8] = const_arr_ref!([u8; 4], |i| i as u8 * 2);
+/// assert_eq!(SLICE, &[0, 2, 4, 6]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_ref {
+    ([$TYPE:ty; 0] $($init:tt)+) => {
+        {
+            const __ARR: [$TYPE; 0] = $crate::const_arr!([$TYPE; 0] $($init)+);
+            &__ARR
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], $($init:tt)+) => {
+        {
+            const __ARR: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], $($init)+);
+            &__ARR
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr]; $VALUE:expr $(,)?) => {
+        {
+            const __ARR: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE]; $VALUE);
+            &__ARR
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const REF: &[TYPE; SIZE] = const_arr_ref!([TYPE; SIZE], INIT_FN);\n e.g. const REF: &[i32;  10  ] = const_arr_ref!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const REF: &[TYPE; SIZE] = const_arr_ref!([TYPE; SIZE], INIT_FN);\n e.g. const REF: &[i32;  10  ] = const_arr_ref!([i32;  10  ], |i| i as i32);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const REF: &[TYPE; SIZE] = const_arr_ref!([TYPE; SIZE], INIT_FN);\n e.g. const REF: &[i32;  10  ] = const_arr_ref!([i32;  10  ], |i| i as i32);\n got:  const_arr_ref!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`make_const_arr`], but also creates `const` with array length.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// make_const_arr_with_len!(ARR_NAME, LEN_NAME, [TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const LEN_NAME: usize = SIZE;
+/// const ARR_NAME: [TYPE; SIZE] = const_arr!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// `macro_rules!` cannot create `ARR_NAME_LEN` identifier from `ARR_NAME`, so
+/// name of length `const` is specified explicitly. Attributes and visibility are
+/// applied to both, so `#[cfg]` keeps or removes them together.
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_const_arr_with_len;
+/// 
+/// make_const_arr_with_len!(TABLE, TABLE_LEN, [u8; 256], |i| i as u8);
+/// assert_eq!(TABLE_LEN, 256);
+/// assert_eq!(TABLE[TABLE_LEN - 1], 255);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_const_arr_with_len {
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $LEN:ident, [$TYPE:ty; $($SIZE:tt)+], $($init:tt)+) => {
+        $(#[$attr])*
+        $vis const $LEN: usize = $($SIZE)+;
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $($SIZE)+], $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $LEN:ident, [$TYPE:ty; $($SIZE:tt)+]; $($init:tt)+) => {
+        $(#[$attr])*
+        $vis const $LEN: usize = $($SIZE)+;
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $($SIZE)+]; $($init)+);
+    };
+    () => { compile_error!("Please specify array name ARR_NAME: \n      make_const_arr_with_len!(ARR_NAME, LEN_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr_with_len!(MY_ARR  , MY_ARR_LEN, [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident $(,)?) => { compile_error!("Please specify length name LEN_NAME: \n      make_const_arr_with_len!(ARR_NAME, LEN_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr_with_len!(MY_ARR  , MY_ARR_LEN, [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $LEN:ident $(,)?) => { compile_error!("Please specify array type TYPE: \n      make_const_arr_with_len!(ARR_NAME, LEN_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr_with_len!(MY_ARR  , MY_ARR_LEN, [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $LEN:ident, [$type:ty; $size:expr] $(,)?) => { compile_error!("Please specify init function INIT_FN: \n      make_const_arr_with_len!(ARR_NAME, LEN_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr_with_len!(MY_ARR  , MY_ARR_LEN, [i32;  1024], |i| i as i32);"); };
+    ($($args:tt)*) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr_with_len!(ARR_NAME, LEN_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr_with_len!(MY_ARR  , MY_ARR_LEN, [i32;  1024], |i| i as i32);\n got:  make_const_arr_with_len!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`make_const_arr`], but wraps array into single-field tuple struct.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// make_const_table!(TABLE_NAME, WRAPPER, [TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const TABLE_NAME: WRAPPER = WRAPPER(const_arr!([TYPE; SIZE], CONST_INIT_FN));
+/// ```
+/// 
+/// - `WRAPPER` is path to tuple struct with single `[TYPE; SIZE]` field,
+///   e.g. `Table` or `tables::Table`
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_const_table;
+/// 
+/// #[repr(transparent)]
+/// struct Table([u32; 256]);
+/// 
+/// make_const_table!(TABLE, Table, [u32; 256], |i| i as u32 * 2);
+/// assert_eq!(TABLE.0[128], 256);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_const_table {
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $WRAP:path, [$TYPE:ty; $SIZE:expr], $($init:tt)+) => {
+        $(#[$attr])*
+        $vis const $NAME: $WRAP = $WRAP($crate::const_arr!([$TYPE; $SIZE], $($init)+));
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $WRAP:path, [$TYPE:ty; $SIZE:expr]; $VALUE:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: $WRAP = $WRAP($crate::const_arr!([$TYPE; $SIZE]; $VALUE));
+    };
+    () => { compile_error!("Please specify table name TABLE_NAME: \n      make_const_table!(TABLE_NAME, WRAPPER, [TYPE; SIZE], INIT_FN);\n e.g. make_const_table!(MY_TABLE  , Table  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident $(,)?) => { compile_error!("Please specify wrapper type WRAPPER: \n      make_const_table!(TABLE_NAME, WRAPPER, [TYPE; SIZE], INIT_FN);\n e.g. make_const_table!(MY_TABLE  , Table  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $WRAP:path $(,)?) => { compile_error!("Please specify array type TYPE: \n      make_const_table!(TABLE_NAME, WRAPPER, [TYPE; SIZE], INIT_FN);\n e.g. make_const_table!(MY_TABLE  , Table  , [i32;  1024], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $WRAP:path, [$type:ty; $size:expr] $(,)?) => { compile_error!("Please specify init function INIT_FN: \n      make_const_table!(TABLE_NAME, WRAPPER, [TYPE; SIZE], INIT_FN);\n e.g. make_const_table!(MY_TABLE  , Table  , [i32;  1024], |i| i as i32);"); };
+    ($($args:tt)*) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_table!(TABLE_NAME, WRAPPER, [TYPE; SIZE], INIT_FN);\n e.g. make_const_table!(MY_TABLE  , Table  , [i32;  1024], |i| i as i32);\n got:  make_const_table!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`make_const_arr`], but passes array to `const fn` constructor.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// make_const_arr_into!(NAME, OUT_TYPE, [TYPE; SIZE], CONSTRUCTOR, CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const NAME: OUT_TYPE = CONSTRUCTOR(const_arr!([TYPE; SIZE], CONST_INIT_FN));
+/// ```
+/// 
+/// - `CONSTRUCTOR` is path to `const fn` from `[TYPE; SIZE]` to `OUT_TYPE`,
+///   e.g. `Buffer::from_array`. Tuple struct name can also be used
+/// - `OUT_TYPE` must be specified, because `const` items cannot have inferred type
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_const_arr_into;
+/// 
+/// struct Buffer {
+///     data: [u8; 8],
+///     len: usize,
+/// }
+/// 
+/// impl Buffer {
+///     const fn from_array(data: [u8; 8]) -> Self {
+///         Buffer { data, len: 8 }
+///     }
+/// }
+/// 
+/// make_const_arr_into!(BUF, Buffer, [u8; 8], Buffer::from_array, |i| i as u8 * 2);
+/// assert_eq!(BUF.data, [0, 2, 4, 6, 8, 10, 12, 14]);
+/// assert_eq!(BUF.len, 8);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_const_arr_into {
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $OUT:ty, [$TYPE:ty; $SIZE:expr], $CTOR:path, $($init:tt)+) => {
+        $(#[$attr])*
+        $vis const $NAME: $OUT = $CTOR($crate::const_arr!([$TYPE; $SIZE], $($init)+));
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $OUT:ty, [$TYPE:ty; $SIZE:expr], $CTOR:path; $VALUE:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: $OUT = $CTOR($crate::const_arr!([$TYPE; $SIZE]; $VALUE));
+    };
+    () => { compile_error!("Please specify name NAME: \n      make_const_arr_into!(NAME, OUT_TYPE, [TYPE; SIZE], CONSTRUCTOR, INIT_FN);\n e.g. make_const_arr_into!(MY_BUF, Buffer  , [u8;   8   ], Buffer::new, |i| i as u8);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident $(,)?) => { compile_error!("Please specify output type OUT_TYPE: \n      make_const_arr_into!(NAME, OUT_TYPE, [TYPE; SIZE], CONSTRUCTOR, INIT_FN);\n e.g. make_const_arr_into!(MY_BUF, Buffer  , [u8;   8   ], Buffer::new, |i| i as u8);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $OUT:ty $(,)?) => { compile_error!("Please specify array type TYPE: \n      make_const_arr_into!(NAME, OUT_TYPE, [TYPE; SIZE], CONSTRUCTOR, INIT_FN);\n e.g. make_const_arr_into!(MY_BUF, Buffer  , [u8;   8   ], Buffer::new, |i| i as u8);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $OUT:ty, [$type:ty; $size:expr] $(,)?) => { compile_error!("Please specify constructor CONSTRUCTOR: \n      make_const_arr_into!(NAME, OUT_TYPE, [TYPE; SIZE], CONSTRUCTOR, INIT_FN);\n e.g. make_const_arr_into!(MY_BUF, Buffer  , [u8;   8   ], Buffer::new, |i| i as u8);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, $OUT:ty, [$type:ty; $size:expr], $CTOR:path $(,)?) => { compile_error!("Please specify init function INIT_FN: \n      make_const_arr_into!(NAME, OUT_TYPE, [TYPE; SIZE], CONSTRUCTOR, INIT_FN);\n e.g. make_const_arr_into!(MY_BUF, Buffer  , [u8;   8   ], Buffer::new, |i| i as u8);"); };
+    ($($args:tt)*) => { compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr_into!(NAME, OUT_TYPE, [TYPE; SIZE], CONSTRUCTOR, INIT_FN);\n e.g. make_const_arr_into!(MY_BUF, Buffer  , [u8;   8   ], Buffer::new, |i| i as u8);\n got:  make_const_arr_into!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Fallible version of [`const_arr`] macro for `Result`-returning init functions.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_try!([TYPE; SIZE], CONST_TRY_INIT_FN);
+/// ```
+/// 
+/// - `CONST_TRY_INIT_FN` is const function or const-like closure from 
+///   `array index`(`usize`) to `Result<TYPE, ERROR>`
+/// - Closure may also specify return type: `|i| -> Result<TYPE, ERROR> { ... }`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_try;
+/// 
+/// const fn parse_digit(b: u8) -> Result<u8, ()> {
+///     match b {
+///         b'0'..=b'9' => Ok(b - b'0'),
+///         _ => Err(()),
+///     }
+/// }
+/// 
+/// const DIGITS: &[u8] = b"31415";
+/// 
+/// const ARR: [u8; 5] = const_arr_try!([u8; 5], |i| parse_digit(DIGITS[i]));
+/// assert_eq!(ARR, [3, 1, 4, 1, 5]);
+/// ```
+/// 
+/// ### Panics
+/// First `Err` aborts compilation with const evaluation error. Const panic messages
+/// cannot be formatted, so failing index is reported as out of bounds access
+/// `the len is 0 but the index is INDEX` right before the panic
+/// (see [`const_arr_try_verbose`] for `&str` errors).
+/// 
+/// ```compile_fail
+/// use const_array_init::const_arr_try;
+/// 
+/// const ARR: [u8; 3] = const_arr_try!([u8; 3], |i| if i < 2 { Ok(i as u8) } else { Err(()) });
+/// # let _ = ARR;
+/// ```
+/// 
+/// Both `TYPE` and `ERROR` must not implement `Drop`, because values are dropped in const context.
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_try {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_try!([$TYPE; $SIZE], |ind| $func_path(ind))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $crate::const_arr_try!([$TYPE; $SIZE], |$name| { let res: $RET = $body; res })
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`,
+            // but every value is unwrapped from Result first.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: usize = 0;
+                    match $body {
+                        Ok(item) => item,
+                        Err(_) => panic!("const_arr_try!: init function returned Err at index 0"),
+                    }
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            let len = arr.len();
+            let mut $name = 1;
+            while $name < len {
+                arr[$name] = match $body {
+                    Ok(item) => item,
+                    Err(_) => {
+                        // Const panic cannot format the index, so report it with
+                        // out of bounds access: "the len is 0 but the index is $name"
+                        let failed_at_index: [usize; 0] = [];
+                        let _index = failed_at_index[$name];
+                        panic!("const_arr_try!: init function returned Err")
+                    }
+                };
+                $name += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_try!([TYPE; SIZE], TRY_INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_try!([u8;   10  ], |i| if i < 10 { Ok(i as u8) } else { Err(()) });"); };
+    ([$type:ty; $size:expr]) => {compile_error!("Please specify init function TRY_INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_try!([TYPE; SIZE], TRY_INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_try!([u8;   10  ], |i| if i < 10 { Ok(i as u8) } else { Err(()) });"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| Ok(i): \n      const ARR: [TYPE; SIZE] = const_arr_try!([TYPE; SIZE], TRY_INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_try!([u8;   10  ], |i| if i < 10 { Ok(i as u8) } else { Err(()) });\n got:  const_arr_try!(", stringify!([$type; $size], $($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr_try`] macro, but failing index and error are shown in compile error.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_try_verbose!([TYPE; SIZE], CONST_TRY_INIT_FN);
+/// ```
+/// 
+/// - `CONST_TRY_INIT_FN` is const function or const-like closure from 
+///   `array index`(`usize`) to `Result<TYPE, &'static str>`
+/// - Const panic messages can display only `&str`, so error must be `&'static str`.
+///   Use [`const_arr_try`] for other error types
+/// - Failing index is found by separate pass over the array, so init function
+///   is called twice for every index before the first `Err`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_try_verbose;
+/// 
+/// const fn parse_digit(b: u8) -> Result<u8, &'static str> {
+///     match b {
+///         b'0'..=b'9' => Ok(b - b'0'),
+///         _ => Err("not a digit"),
+///     }
+/// }
+/// 
+/// const DIGITS: &[u8] = b"31415";
+/// 
+/// const ARR: [u8; 5] = const_arr_try_verbose!([u8; 5], |i| parse_digit(DIGITS[i]));
+/// assert_eq!(ARR, [3, 1, 4, 1, 5]);
+/// ```
+/// 
+/// ### Panics
+/// First `Err` aborts compilation with two const evaluation errors: panic with the error
+/// as message, and out of bounds access `the len is 0 but the index is INDEX`, because
+/// const panic messages cannot format failing index:
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_try_verbose;
+/// 
+/// const ARR: [u8; 3] = const_arr_try_verbose!([u8; 3], |i| if i < 2 { Ok(i as u8) } else { Err("too big") });
+/// // panicked: too big
+/// // index out of bounds: the length is 0 but the index is 2
+/// # let _ = ARR;
+/// ```
+/// 
+/// `TYPE` must not implement `Drop`, because values are dropped in const context.
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_try_verbose {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_try_verbose!([$TYPE; $SIZE], |ind| $func_path(ind))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            // Const panic can display `&str` error, but cannot format the index.
+            // So failing index is found first and reported by separate constant
+            // with out of bounds access: "the len is 0 but the index is INDEX".
+            const __FAILED: Option<(usize, &str)> = {
+                let mut failed = None;
+                let mut $name = 0;
+                while $name < $SIZE {
+                    let res: Result<$TYPE, &str> = $body;
+                    if let Err(err) = res {
+                        failed = Some(($name, err));
+                        break;
+                    }
+                    $name += 1;
+                }
+                failed
+            };
+            const _: () = if let Some((index, _)) = __FAILED {
+                let failed_at_index: [usize; 0] = [];
+                let _index = failed_at_index[index];
+            };
+
+            // Same seed-then-overwrite approach as in `const_arr_try!`,
+            // but error is shown in panic message.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: usize = 0;
+                    let res: Result<$TYPE, &str> = $body;
+                    match res {
+                        Ok(item) => item,
+                        Err(err) => panic!("{}", err),
+                    }
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            let mut $name = 1;
+            while $name < arr.len() {
+                let res: Result<$TYPE, &str> = $body;
+                arr[$name] = match res {
+                    Ok(item) => item,
+                    Err(err) => panic!("{}", err),
+                };
+                $name += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_try_verbose!([TYPE; SIZE], TRY_INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_try_verbose!([u8;   10  ], |i| if i < 10 { Ok(i as u8) } else { Err(\"too big\") });"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function TRY_INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_try_verbose!([TYPE; SIZE], TRY_INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_try_verbose!([u8;   10  ], |i| if i < 10 { Ok(i as u8) } else { Err(\"too big\") });"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| Ok(i): \n      const ARR: [TYPE; SIZE] = const_arr_try_verbose!([TYPE; SIZE], TRY_INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_try_verbose!([u8;   10  ], |i| if i < 10 { Ok(i as u8) } else { Err(\"too big\") });\n got:  const_arr_try_verbose!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_try_verbose!([TYPE; SIZE], TRY_INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_try_verbose!([u8;   10  ], |i| if i < 10 { Ok(i as u8) } else { Err(\"too big\") });\n got:  const_arr_try_verbose!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Checked version of [`const_arr`] macro for integer tables.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_checked!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - `TYPE` is primitive integer type
+/// - `CONST_INIT_FN` is const function or const-like closure from 
+///   `array index`(`usize`) to any primitive integer type. Result is converted to `TYPE`
+///   and converted back to check that value wasn't truncated or wrapped
+/// 
+/// `const_arr!([u8; N], |i| (i * 10) as u8)` silently wraps values over `255`,
+/// while this macro aborts compilation.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_checked;
+/// 
+/// const ARR: [u8; 5] = const_arr_checked!([u8; 5], |i| i * 10);
+/// assert_eq!(ARR, [0, 10, 20, 30, 40]);
+/// ```
+/// 
+/// ### Panics
+/// Value that doesn't fit in `TYPE` aborts compilation with const evaluation error.
+/// Const panic messages cannot be formatted, so failing index is reported as out of bounds
+/// access `the len is 0 but the index is INDEX` right before the panic.
+/// 
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_checked;
+/// 
+/// const ARR: [u8; 30] = const_arr_checked!([u8; 30], |i| i * 10);
+/// //                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ index out of bounds: the length is 0 but the index is 26
+/// # let _ = ARR;
+/// ```
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_checked;
+/// 
+/// const ARR: [u8; 3] = const_arr_checked!([u8; 3], |i| i as i32 - 1);
+/// # let _ = ARR;
+/// ```
+/// 
+/// Values are compared as `i128`, so `u128` values over `i128::MAX` aren't checked
+/// when `TYPE` is `i128`.
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_checked {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_checked!([$TYPE; $SIZE], |ind| $func_path(ind))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`,
+            // but every value is converted to $TYPE and back to check that it fits.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: usize = 0;
+                    let wide = ($body) as i128;
+                    let item = wide as $TYPE;
+                    if item as i128 != wide {
+                        panic!("const_arr_checked!: value doesn't fit in array type at index 0");
+                    }
+                    item
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            let mut $name = 1;
+            while $name < arr.len() {
+                let wide = ($body) as i128;
+                let item = wide as $TYPE;
+                if item as i128 != wide {
+                    // Const panic cannot format the index, so report it with
+                    // out of bounds access: "the len is 0 but the index is $name"
+                    let failed_at_index: [usize; 0] = [];
+                    let _index = failed_at_index[$name];
+                    panic!("const_arr_checked!: value doesn't fit in array type")
+                }
+                arr[$name] = item;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_checked!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_checked!([u8;   10  ], |i| i * 10);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_checked!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_checked!([u8;   10  ], |i| i * 10);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i * 10: \n      const ARR: [TYPE; SIZE] = const_arr_checked!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [u8;   10  ] = const_arr_checked!([u8;   10  ], |i| i * 10);\n got:  const_arr_checked!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but checks that init function depends on index.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_strict!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - First and last values are compared with `==` in constant context, so `TYPE`
+///   must be primitive type (integers, `bool`, `char`)
+/// - Arrays with less than 2 values aren't checked
+/// 
+/// Catches init functions that accidentally ignore index, e.g. `|i| 5` instead of `|i| i * 5`.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_strict;
+/// 
+/// const ARR: [i32; 5] = const_arr_strict!([i32; 5], |i| i as i32);
+/// assert_eq!(ARR, [0, 1, 2, 3, 4]);
+/// ```
+/// 
+/// ### Panics
+/// Equal first and last values abort compilation with const evaluation error.
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_strict;
+/// 
+/// const ARR: [i32; 5] = const_arr_strict!([i32; 5], |i| 5);
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_strict {
+    ([$TYPE:ty; $SIZE:expr], $($init:tt)+) => {
+        {
+            let arr: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], $($init)+);
+            if arr.len() > 1 && arr[0] == arr[arr.len() - 1] {
+                panic!("const_arr_strict!: first and last values are equal, init function may not depend on index");
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_strict!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_strict!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_strict!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_strict!([i32;  10  ], |i| i as i32);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_strict!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_strict!([i32;  10  ], |i| i as i32);\n got:  const_arr_strict!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to initialize 2D arrays in constant context
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [[TYPE; COLS]; ROWS] = const_arr_2d!([[TYPE; COLS]; ROWS], CONST_INIT_FN);
+/// ```
+/// 
+/// - `CONST_INIT_FN` is const function or const-like closure from 
+///   `row` and `column` indices (`usize`, `usize`) to `TYPE`
+/// - Array is filled in row-major order
+/// - `COLS` cannot be generic parameter, because seed row is a separate `const` item.
+///   Use [`const_arr_generic`] for generic sizes of `Copy` types
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_2d;
+/// 
+/// const IDENTITY: [[i32; 3]; 3] = const_arr_2d!([[i32; 3]; 3], |row, col| (row == col) as i32);
+/// assert_eq!(IDENTITY, [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+/// 
+/// const fn mul(row: usize, col: usize) -> usize {
+///     row * col
+/// }
+/// 
+/// const TABLE: [[usize; 3]; 2] = const_arr_2d!([[usize; 3]; 2], mul);
+/// assert_eq!(TABLE, [[0, 0, 0], [0, 1, 2]]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_2d {
+    ([[$TYPE:ty; $COLS:expr]; $ROWS:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_2d!([[$TYPE; $COLS]; $ROWS], |row, col| $func_path(row, col))
+    };
+    ([[$TYPE:ty; $COLS:expr]; $ROWS:expr], |$row:ident, $col:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`, applied to
+            // both dimensions: seed row is made of seed items and array is
+            // made of seed rows. Both have to be constants to be repeated.
+            let mut arr: [[$TYPE; $COLS]; $ROWS] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $row: usize = 0;
+                    #[allow(unused_variables)]
+                    let $col: usize = 0;
+                    $body
+                };
+                const __TEMP_ROW: [$TYPE; $COLS] = [__TEMP_ITEM; $COLS];
+                [__TEMP_ROW; $ROWS]
+            };
+
+            // Item at (0, 0) already holds $body evaluated at (0, 0) from __TEMP_ITEM.
+            let mut $row = 0;
+            while $row < arr.len() {
+                let mut $col = if $row == 0 { 1 } else { 0 };
+                while $col < arr[$row].len() {
+                    arr[$row][$col] = $body;
+                    $col += 1;
+                }
+                $row += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [[TYPE; COLS]; ROWS] = const_arr_2d!([[TYPE; COLS]; ROWS], INIT_FN);\n e.g. const ARR: [[i32;  3  ];  3  ] = const_arr_2d!([[i32;  3  ];  3  ], |row, col| (row * col) as i32);"); };
+    ([[$type:ty; $cols:expr]; $rows:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [[TYPE; COLS]; ROWS] = const_arr_2d!([[TYPE; COLS]; ROWS], INIT_FN);\n e.g. const ARR: [[i32;  3  ];  3  ] = const_arr_2d!([[i32;  3  ];  3  ], |row, col| (row * col) as i32);"); };
+    ([[$type:ty; $cols:expr]; $rows:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |row, col| row * col: \n      const ARR: [[TYPE; COLS]; ROWS] = const_arr_2d!([[TYPE; COLS]; ROWS], INIT_FN);\n e.g. const ARR: [[i32;  3  ];  3  ] = const_arr_2d!([[i32;  3  ];  3  ], |row, col| (row * col) as i32);\n got:  const_arr_2d!(", stringify!([[$type; $cols]; $rows], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [[TYPE; COLS]; ROWS]: \n      const ARR: [[TYPE; COLS]; ROWS] = const_arr_2d!([[TYPE; COLS]; ROWS], INIT_FN);\n e.g. const ARR: [[i32;  3  ];  3  ] = const_arr_2d!([[i32;  3  ];  3  ], |row, col| (row * col) as i32);\n got:  const_arr_2d!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr_2d`] macro, but creates flat array of `ROWS * COLS` items
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_grid!([TYPE; SIZE], ROWS, COLS, CONST_INIT_FN);
+/// ```
+/// 
+/// - `CONST_INIT_FN` is const function or const-like closure from 
+///   `row` and `column` indices (`usize`, `usize`) to `TYPE`
+/// - Item for `(row, col)` is stored at index `row * COLS + col`
+/// - `SIZE` must be equal to `ROWS * COLS`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_grid;
+/// 
+/// const GRID: [usize; 6] = const_arr_grid!([usize; 2 * 3], 2, 3, |row, col| row * 10 + col);
+/// assert_eq!(GRID, [0, 1, 2, 10, 11, 12]);
+/// ```
+/// 
+/// ### Panics
+/// `SIZE` which isn't equal to `ROWS * COLS` aborts compilation with const evaluation error.
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_grid;
+/// 
+/// const GRID: [usize; 5] = const_arr_grid!([usize; 5], 2, 3, |row, col| row + col);
+/// # let _ = GRID;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_grid {
+    ([$TYPE:ty; $SIZE:expr], $ROWS:expr, $COLS:expr, $func_path:path $(,)?) => {
+        $crate::const_arr_grid!([$TYPE; $SIZE], $ROWS, $COLS, |row, col| $func_path(row, col))
+    };
+    ([$TYPE:ty; $SIZE:expr], $ROWS:expr, $COLS:expr, |$row:ident, $col:ident| $body:expr $(,)?) => {
+        {
+            let rows: usize = $ROWS;
+            let cols: usize = $COLS;
+            if rows * cols != $SIZE {
+                panic!("const_arr_grid!: array size must be equal to ROWS * COLS");
+            }
+            // Same seed-then-overwrite approach as in `const_arr_2d!`, but array is flat.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $row: usize = 0;
+                    #[allow(unused_variables)]
+                    let $col: usize = 0;
+                    $body
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            // Item at (0, 0) already holds $body evaluated at (0, 0) from __TEMP_ITEM.
+            let mut $row = 0;
+            while $row < rows {
+                let mut $col = if $row == 0 { 1 } else { 0 };
+                while $col < cols {
+                    arr[$row * cols + $col] = $body;
+                    $col += 1;
+                }
+                $row += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_grid!([TYPE; SIZE], ROWS, COLS, INIT_FN);\n e.g. const ARR: [i32;  6   ] = const_arr_grid!([i32;  2 * 3], 2, 3, |row, col| (row * col) as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify grid dimensions ROWS and COLS: \n      const ARR: [TYPE; SIZE] = const_arr_grid!([TYPE; SIZE], ROWS, COLS, INIT_FN);\n e.g. const ARR: [i32;  6   ] = const_arr_grid!([i32;  2 * 3], 2, 3, |row, col| (row * col) as i32);"); };
+    ([$type:ty; $size:expr], $rows:expr, $cols:expr $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_grid!([TYPE; SIZE], ROWS, COLS, INIT_FN);\n e.g. const ARR: [i32;  6   ] = const_arr_grid!([i32;  2 * 3], 2, 3, |row, col| (row * col) as i32);"); };
+    ([$type:ty; $size:expr], $rows:expr, $cols:expr, $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |row, col| row * col: \n      const ARR: [TYPE; SIZE] = const_arr_grid!([TYPE; SIZE], ROWS, COLS, INIT_FN);\n e.g. const ARR: [i32;  6   ] = const_arr_grid!([i32;  2 * 3], 2, 3, |row, col| (row * col) as i32);\n got:  const_arr_grid!(", stringify!([$type; $size], $rows, $cols, $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_grid!([TYPE; SIZE], ROWS, COLS, INIT_FN);\n e.g. const ARR: [i32;  6   ] = const_arr_grid!([i32;  2 * 3], 2, 3, |row, col| (row * col) as i32);\n got:  const_arr_grid!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but fills array from the last index down to 0.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_rev!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// It is useful with two-argument closure `|i, arr|`, where `arr` is read-only
+/// slice of the array filled so far:
+/// - `arr[j]` for `j > i` is already computed.
+/// - `arr[j]` for `j <= i` still holds the seed value, which is body evaluated at index `SIZE - 1`.
+/// - At index `SIZE - 1` `arr` is an empty slice, because there is no array yet.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_rev;
+/// 
+/// const DISTANCES: [u32; 5] = const_arr_rev!([u32; 5], |i, arr| {
+///     if arr.is_empty() { 0 } else { arr[i + 1] + 1 }
+/// });
+/// assert_eq!(DISTANCES, [4, 3, 2, 1, 0]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_rev {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_rev!([$TYPE; $SIZE], |ind, _arr| $func_path(ind))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        $crate::const_arr_rev!([$TYPE; $SIZE], |$name, _arr| $body)
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident, $arr:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`,
+            // but seed is computed for the last index.
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: usize = usize::saturating_sub($SIZE, 1);
+                    #[allow(unused_variables)]
+                    let $arr: &[$TYPE] = &[];
+                    $body
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            // Last index already holds its value from __TEMP_ITEM, so start from the one before.
+            let mut ind = arr.len().saturating_sub(1);
+            while ind > 0 {
+                ind -= 1;
+                let $name = ind;
+                let item = {
+                    let $arr: &[$TYPE] = &arr;
+                    $body
+                };
+                arr[ind] = item;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_rev!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_rev!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_rev!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_rev!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i or |i, arr| arr[i + 1]: \n      const ARR: [TYPE; SIZE] = const_arr_rev!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_rev!([i32;  10  ], |i| i as i32);\n got:  const_arr_rev!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_rev!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_rev!([i32;  10  ], |i| i as i32);\n got:  const_arr_rev!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to initialize arrays in constant context by repeating a pattern
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_cycle!([TYPE; SIZE], PATTERN);
+/// ```
+/// 
+/// - `PATTERN` is constant array or slice of `TYPE`. `TYPE` must be `Copy`
+/// - Every item is `PATTERN[i % PATTERN.len()]`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_cycle;
+/// 
+/// const PAT: [u8; 3] = [0xDE, 0xAD, 0xBE];
+/// 
+/// const ARR: [u8; 5] = const_arr_cycle!([u8; 5], PAT);
+/// assert_eq!(ARR, [0xDE, 0xAD, 0xBE, 0xDE, 0xAD]);
+/// ```
+/// 
+/// Empty pattern is a compile error:
+/// ```compile_fail
+/// use const_array_init::const_arr_cycle;
+/// 
+/// const PAT: [u8; 0] = [];
+/// 
+/// const ARR: [u8; 5] = const_arr_cycle!([u8; 5], PAT);
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_cycle {
+    ([$TYPE:ty; $SIZE:expr], $PAT:expr $(,)?) => {
+        {
+            const __TEMP_ITEM: $TYPE = {
+                if $PAT.len() == 0 {
+                    panic!("const_arr_cycle!: pattern must not be empty");
+                }
+                $PAT[0]
+            };
+            let mut arr: [$TYPE; $SIZE] = [__TEMP_ITEM; $SIZE];
+
+            // Index 0 already holds PATTERN[0] from __TEMP_ITEM, so start from 1.
+            let mut ind = 1;
+            while ind < arr.len() {
+                arr[ind] = $PAT[ind % $PAT.len()];
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_cycle!([TYPE; SIZE], PATTERN);\n e.g. const ARR: [u8;   10  ] = const_arr_cycle!([u8;   10  ], [1, 2, 3]);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify pattern PATTERN: \n      const ARR: [TYPE; SIZE] = const_arr_cycle!([TYPE; SIZE], PATTERN);\n e.g. const ARR: [u8;   10  ] = const_arr_cycle!([u8;   10  ], [1, 2, 3]);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_cycle!([TYPE; SIZE], PATTERN);\n e.g. const ARR: [u8;   10  ] = const_arr_cycle!([u8;   10  ], [1, 2, 3]);\n got:  const_arr_cycle!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but also passes index within repeating tile
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_tiled!([TYPE; SIZE], TILE_LEN, |i, j| BODY);
+/// ```
+/// 
+/// - `i` is index in array and `j` is `i % TILE_LEN` - index within the tile
+/// - `CONST_INIT_FN` can also be `const fn(usize, usize) -> TYPE` called with `i` and `j`
+/// - `TILE_LEN` is constant `usize` expression, which must not be 0
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_tiled;
+/// 
+/// const RAMP: [u8; 7] = const_arr_tiled!([u8; 7], 3, |i, j| (i / 3 * 100 + j * 10) as u8);
+/// assert_eq!(RAMP, [0, 10, 20, 100, 110, 120, 200]);
+/// ```
+/// 
+/// ### Panics
+/// Tile length 0 aborts compilation with const evaluation error.
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_tiled;
+/// 
+/// const ARR: [usize; 4] = const_arr_tiled!([usize; 4], 0, |i, j| i + j);
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_tiled {
+    ([$TYPE:ty; $SIZE:expr], $TILE:expr, $func_path:path $(,)?) => {
+        {
+            let tile = $TILE;
+            if tile == 0 {
+                panic!("const_arr_tiled!: tile length must not be 0");
+            }
+            const __TEMP_ITEM: $TYPE = $func_path(0, 0);
+            let mut arr: [$TYPE; $SIZE] = [__TEMP_ITEM; $SIZE];
+
+            // Index 0 already holds $func_path(0, 0) from __TEMP_ITEM, so start from 1.
+            let len = arr.len();
+            let mut ind = 1;
+            while ind < len {
+                arr[ind] = $func_path(ind, ind % tile);
+                ind += 1;
+            }
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], $TILE:expr, |$name:ident, $tile_name:ident| $body:expr $(,)?) => {
+        {
+            let tile = $TILE;
+            if tile == 0 {
+                panic!("const_arr_tiled!: tile length must not be 0");
+            }
+            let mut arr: [$TYPE; $SIZE] = {
+                const __TEMP_ITEM: $TYPE = {
+                    #[allow(unused_variables)]
+                    let $name: usize = 0;
+                    #[allow(unused_variables)]
+                    let $tile_name: usize = 0;
+                    $body
+                };
+                [__TEMP_ITEM; $SIZE]
+            };
+
+            // Index 0 already holds BODY for (0, 0) from __TEMP_ITEM, so start from 1.
+            let len = arr.len();
+            let mut $name = 1;
+            while $name < len {
+                #[allow(unused_variables)]
+                let $tile_name = $name % tile;
+                arr[$name] = $body;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_tiled!([TYPE; SIZE], TILE_LEN, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_tiled!([i32;  10  ], 3, |i, j| j as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify tile length TILE_LEN: \n      const ARR: [TYPE; SIZE] = const_arr_tiled!([TYPE; SIZE], TILE_LEN, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_tiled!([i32;  10  ], 3, |i, j| j as i32);"); };
+    ([$type:ty; $size:expr], $tile:expr $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_tiled!([TYPE; SIZE], TILE_LEN, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_tiled!([i32;  10  ], 3, |i, j| j as i32);"); };
+    ([$type:ty; $size:expr], $tile:expr, $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i, j| j: \n      const ARR: [TYPE; SIZE] = const_arr_tiled!([TYPE; SIZE], TILE_LEN, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_tiled!([i32;  10  ], 3, |i, j| j as i32);\n got:  const_arr_tiled!(", stringify!([$type; $size], $tile, $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_tiled!([TYPE; SIZE], TILE_LEN, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_tiled!([i32;  10  ], 3, |i, j| j as i32);\n got:  const_arr_tiled!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to map existing constant array to a new one in constant context
+/// 
+/// Usage:
+/// ```ignore
+/// const DST: [TYPE; SIZE] = const_map_arr!(SRC, |x| BODY);
+/// const DST: [TYPE; SIZE] = const_map_arr!([TYPE; SIZE], SRC, |x| BODY);
+/// ```
+/// 
+/// - `SRC` is constant array. Its items are copied into `x`, so they must be `Copy`
+/// - Without `[TYPE; SIZE]` item type is inferred from `BODY` and must be `Copy`
+/// - With `[TYPE; SIZE]` item type can be any type, `SIZE` must be equal to `SRC.len()`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_map_arr;
+/// 
+/// const SRC: [i32; 3] = [1, 2, 3];
+/// 
+/// const DST: [i64; 3] = const_map_arr!(SRC, |x| x as i64 * 2);
+/// assert_eq!(DST, [2, 4, 6]);
+/// ```
+/// 
+/// `SIZE` that isn't equal to `SRC.len()` is a compile error, reported before `BODY`
+/// is evaluated for any item:
+/// ```compile_fail,E0080
+/// use const_array_init::const_map_arr;
+/// 
+/// const SRC: [u8; 3] = [0, 1, 2];
+/// 
+/// const DST: [u8; 4] = const_map_arr!([u8; 4], SRC, |x| 10 / x);
+/// //                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ const_map_arr!: SIZE is not equal to length of source array
+/// # let _ = DST;
+/// ```
+/// 
+/// - See [`make_const_map_arr`]
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_map_arr {
+    ([$TYPE:ty; $SIZE:expr], $SRC:expr, |$x:ident| $body:expr $(,)?) => {
+        {
+            // Length is checked in seed, so mismatch is reported
+            // before init function is called for SRC[0].
+            const __TEMP_ITEM: $TYPE = {
+                if $SIZE != $SRC.len() {
+                    panic!("const_map_arr!: SIZE is not equal to length of source array");
+                }
+                let $x = $SRC[0];
+                $body
+            };
+            let mut arr: [$TYPE; $SIZE] = [__TEMP_ITEM; $SIZE];
+
+            // Index 0 already holds mapped SRC[0] from __TEMP_ITEM, so start from 1.
+            let mut ind = 1;
+            while ind < arr.len() {
+                let $x = $SRC[ind];
+                arr[ind] = $body;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    ($SRC:expr, |$x:ident| $body:expr $(,)?) => {
+        {
+            // Item type is unknown, so seed cannot be a constant and
+            // is repeated as a value. That's why it has to be `Copy`.
+            let mut arr = {
+                let $x = $SRC[0];
+                [$body; $SRC.len()]
+            };
+
+            let mut ind = 1;
+            while ind < arr.len() {
+                let $x = $SRC[ind];
+                arr[ind] = $body;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify source array SRC: \n      const DST: [TYPE; SIZE] = const_map_arr!(SRC, MAP_FN);\n e.g. const DST: [i64;  10  ] = const_map_arr!(SRC, |x| x as i64);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Map function has wrong format. It should be |x| x: \n      const DST: [TYPE; SIZE] = const_map_arr!(SRC, MAP_FN);\n e.g. const DST: [i64;  10  ] = const_map_arr!(SRC, |x| x as i64);\n got:  const_map_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Wrapper around [`const_map_arr`] macro. Allows to specify the type of an array `single` time.
+/// 
+/// Usage:
+/// ```ignore
+/// make_const_map_arr!(DST_NAME, [TYPE; SIZE], SRC, |x| BODY);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const DST_NAME: [TYPE; SIZE] = const_map_arr!([TYPE; SIZE], SRC, |x| BODY);
+/// ```
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_const_map_arr;
+/// 
+/// const SRC: [u8; 4] = [1, 2, 3, 4];
+/// 
+/// make_const_map_arr!(DST, [u16; 4], SRC, |x| x as u16 * 100);
+/// assert_eq!(DST, [100, 200, 300, 400]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_const_map_arr {
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; $SIZE:expr], $SRC:expr, |$x:ident| $body:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $SIZE] = $crate::const_map_arr!([$TYPE; $SIZE], $SRC, |$x| $body);
+    };
+    () => { compile_error!("Please specify array name DST_NAME: \n      make_const_map_arr!(DST_NAME, [TYPE; SIZE], SRC, MAP_FN);\n e.g. make_const_map_arr!(MY_ARR  , [i64;  1024], SRC, |x| x as i64);"); };
+    ($($args:tt)*) => { compile_error!(concat!("Wrong format. It should be DST_NAME, [TYPE; SIZE], SRC, |x| x: \n      make_const_map_arr!(DST_NAME, [TYPE; SIZE], SRC, MAP_FN);\n e.g. make_const_map_arr!(MY_ARR  , [i64;  1024], SRC, |x| x as i64);\n got:  make_const_map_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but array length is taken from other constant array
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; OTHER.len()] = const_arr_like!([TYPE; _], OTHER, CONST_INIT_FN);
+/// const ARR: [TYPE; OTHER.len()] = const_arr_like!(OTHER, |i| -> TYPE { BODY });
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const ARR: [TYPE; OTHER.len()] = const_arr!([TYPE; OTHER.len()], CONST_INIT_FN);
+/// ```
+/// 
+/// - `OTHER` is constant array. Only its length is used, item types can be different
+/// - `TYPE` can be omitted if closure specifies return type.
+///   Use [`make_const_arr_like`] to specify it once for `const` item
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_like;
+/// 
+/// const KEYS: [u8; 4] = [10, 20, 30, 40];
+/// 
+/// const VALUES: [u16; KEYS.len()] = const_arr_like!(KEYS, |i| -> u16 { KEYS[i] as u16 * 100 });
+/// assert_eq!(VALUES, [1000, 2000, 3000, 4000]);
+/// 
+/// const fn square(n: usize) -> u64 {
+///     (n * n) as u64
+/// }
+/// 
+/// const SQUARES: [u64; KEYS.len()] = const_arr_like!([u64; _], KEYS, square);
+/// assert_eq!(SQUARES, [0, 1, 4, 9]);
+/// ```
+/// 
+/// Item type isn't inferred from `const` item type, so closure without return
+/// type is a compile error:
+/// ```compile_fail
+/// use const_array_init::const_arr_like;
+/// 
+/// const KEYS: [u8; 4] = [10, 20, 30, 40];
+/// 
+/// const VALUES: [u16; KEYS.len()] = const_arr_like!(KEYS, |i| KEYS[i] as u16 * 100);
+/// //                                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Item type cannot be inferred
+/// # let _ = VALUES;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_like {
+    ([$TYPE:ty; _], $OTHER:expr, $($init:tt)+) => {
+        $crate::const_arr!([$TYPE; $OTHER.len()], $($init)+)
+    };
+    ([$TYPE:ty; _], $OTHER:expr; $VALUE:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; $OTHER.len()]; $VALUE)
+    };
+    ($OTHER:expr, |$name:ident| -> $RET:ty $body:block $(,)?) => {
+        $crate::const_arr!([_; $OTHER.len()], |$name| -> $RET $body)
+    };
+    () => {compile_error!("Please specify array OTHER: \n      const ARR: [TYPE; OTHER.len()] = const_arr_like!([TYPE; _], OTHER, INIT_FN);\n e.g. const ARR: [i32;  OTHER.len()] = const_arr_like!([i32;  _], OTHER, |i| i as i32);"); };
+    ([$type:ty; _] $(,)?) => {compile_error!("Please specify array OTHER and init function INIT_FN: \n      const ARR: [TYPE; OTHER.len()] = const_arr_like!([TYPE; _], OTHER, INIT_FN);\n e.g. const ARR: [i32;  OTHER.len()] = const_arr_like!([i32;  _], OTHER, |i| i as i32);"); };
+    ([$type:ty; _], $other:expr $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; OTHER.len()] = const_arr_like!([TYPE; _], OTHER, INIT_FN);\n e.g. const ARR: [i32;  OTHER.len()] = const_arr_like!([i32;  _], OTHER, |i| i as i32);"); };
+    ($other:expr $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; OTHER.len()] = const_arr_like!([TYPE; _], OTHER, INIT_FN);\n e.g. const ARR: [i32;  OTHER.len()] = const_arr_like!([i32;  _], OTHER, |i| i as i32);"); };
+    ($other:expr, $($args:tt)*) => {compile_error!(concat!("Item type cannot be inferred. Please specify array type [TYPE; _] or closure return type: \n      const ARR: [TYPE; OTHER.len()] = const_arr_like!([TYPE; _], OTHER, INIT_FN);\n e.g. const ARR: [i32;  OTHER.len()] = const_arr_like!(OTHER, |i| -> i32 { i as i32 });\n got:  const_arr_like!(", stringify!($other, $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be [TYPE; _], OTHER, |i| i: \n      const ARR: [TYPE; OTHER.len()] = const_arr_like!([TYPE; _], OTHER, INIT_FN);\n e.g. const ARR: [i32;  OTHER.len()] = const_arr_like!([i32;  _], OTHER, |i| i as i32);\n got:  const_arr_like!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Wrapper around [`const_arr`] macro. Array length is taken from other constant array.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// make_const_arr_like!(ARR_NAME, [TYPE; _], OTHER, CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// const ARR_NAME: [TYPE; OTHER.len()] = const_arr!([TYPE; OTHER.len()], CONST_INIT_FN);
+/// ```
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_const_arr_like;
+/// 
+/// const NAMES: [&str; 3] = ["a", "bb", "ccc"];
+/// 
+/// make_const_arr_like!(LENGTHS, [usize; _], NAMES, |i| NAMES[i].len());
+/// assert_eq!(LENGTHS, [1, 2, 3]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_const_arr_like {
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; _], $OTHER:expr, $($init:tt)+) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $OTHER.len()] = $crate::const_arr!([$TYPE; $OTHER.len()], $($init)+);
+    };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$TYPE:ty; _], $OTHER:expr; $VALUE:expr $(,)?) => {
+        $(#[$attr])*
+        $vis const $NAME: [$TYPE; $OTHER.len()] = $crate::const_arr!([$TYPE; $OTHER.len()]; $VALUE);
+    };
+    () => { compile_error!("Please specify array name ARR_NAME: \n      make_const_arr_like!(ARR_NAME, [TYPE; _], OTHER, INIT_FN);\n e.g. make_const_arr_like!(MY_ARR  , [i32;  _], OTHER, |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident $(,)?) => { compile_error!("Please specify array type [TYPE; _]: \n      make_const_arr_like!(ARR_NAME, [TYPE; _], OTHER, INIT_FN);\n e.g. make_const_arr_like!(MY_ARR  , [i32;  _], OTHER, |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty; _] $(,)?) => { compile_error!("Please specify array OTHER and init function INIT_FN: \n      make_const_arr_like!(ARR_NAME, [TYPE; _], OTHER, INIT_FN);\n e.g. make_const_arr_like!(MY_ARR  , [i32;  _], OTHER, |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis $NAME:ident, [$type:ty; _], $other:expr $(,)?) => { compile_error!("Please specify init function INIT_FN: \n      make_const_arr_like!(ARR_NAME, [TYPE; _], OTHER, INIT_FN);\n e.g. make_const_arr_like!(MY_ARR  , [i32;  _], OTHER, |i| i as i32);"); };
+    ($($args:tt)*) => { compile_error!(concat!("Wrong format. It should be ARR_NAME, [TYPE; _], OTHER, |i| i: \n      make_const_arr_like!(ARR_NAME, [TYPE; _], OTHER, INIT_FN);\n e.g. make_const_arr_like!(MY_ARR  , [i32;  _], OTHER, |i| i as i32);\n got:  make_const_arr_like!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Wrapper around [`make_const_arr`] macro. Creates same array for multiple sizes.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// make_const_arrs!([(NAME1, SIZE1), (NAME2, SIZE2), ...], [TYPE; _], CONST_INIT_FN);
+/// ```
+/// 
+/// Desugars to:
+/// ```ignore
+/// make_const_arr!(NAME1, [TYPE; SIZE1], CONST_INIT_FN);
+/// make_const_arr!(NAME2, [TYPE; SIZE2], CONST_INIT_FN);
+/// ```
+/// 
+/// - Attributes and visibility are applied to every array
+/// 
+/// Examples:
+/// ```
+/// use const_array_init::make_const_arrs;
+/// 
+/// make_const_arrs!(pub [(RAMP4, 4), (RAMP8, 8)], [u8; _], |i| i as u8 * 2);
+/// assert_eq!(RAMP4, [0, 2, 4, 6]);
+/// assert_eq!(RAMP8[7], 14);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! make_const_arrs {
+    // Init function is repeated for every array, while names and sizes are in
+    // different repetition, so arrays are created one by one with recursion.
+    (@each [$(#[$attr:meta])*] [$vis:vis] [$TYPE:ty] [$($init:tt)+]) => {};
+    (@each [$(#[$attr:meta])*] [$vis:vis] [$TYPE:ty] [$($init:tt)+] ($NAME:ident, $SIZE:expr) $($rest:tt)*) => {
+        $crate::make_const_arr!($(#[$attr])* $vis $NAME, [$TYPE; $SIZE] $($init)+);
+        $crate::make_const_arrs!(@each [$(#[$attr])*] [$vis] [$TYPE] [$($init)+] $($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis [$(($NAME:ident, $SIZE:expr)),+ $(,)?], [$TYPE:ty; _] $($init:tt)+) => {
+        $crate::make_const_arrs!(@each [$(#[$attr])*] [$vis] [$TYPE] [$($init)+] $(($NAME, $SIZE))+);
+    };
+    () => { compile_error!("Please specify array names and sizes [(NAME, SIZE), ...]: \n      make_const_arrs!([(NAME1, SIZE1), (NAME2, SIZE2), ...], [TYPE; _], INIT_FN);\n e.g. make_const_arrs!([(ARR16, 16), (ARR32, 32)], [i32;  _], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis [$(($name:ident, $size:expr)),+ $(,)?] $(,)?) => { compile_error!("Please specify array type [TYPE; _]: \n      make_const_arrs!([(NAME1, SIZE1), (NAME2, SIZE2), ...], [TYPE; _], INIT_FN);\n e.g. make_const_arrs!([(ARR16, 16), (ARR32, 32)], [i32;  _], |i| i as i32);"); };
+    ($(#[$attr:meta])* $vis:vis [$(($name:ident, $size:expr)),+ $(,)?], [$type:ty; _] $(,)?) => { compile_error!("Please specify init function INIT_FN: \n      make_const_arrs!([(NAME1, SIZE1), (NAME2, SIZE2), ...], [TYPE; _], INIT_FN);\n e.g. make_const_arrs!([(ARR16, 16), (ARR32, 32)], [i32;  _], |i| i as i32);"); };
+    ($($args:tt)*) => { compile_error!(concat!("Wrong format. It should be [(NAME, SIZE), ...], [TYPE; _], |i| i: \n      make_const_arrs!([(NAME1, SIZE1), (NAME2, SIZE2), ...], [TYPE; _], INIT_FN);\n e.g. make_const_arrs!([(ARR16, 16), (ARR32, 32)], [i32;  _], |i| i as i32);\n got:  make_const_arrs!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to copy part of constant array in constant context
+/// 
+/// Usage:
+/// ```ignore
+/// const DST: [TYPE; LEN] = const_subarr!(SRC, START, LEN);
+/// ```
+/// 
+/// - `SRC` is constant array or slice of `Copy` items
+/// - `DST` holds `SRC[START..START + LEN]`
+/// - `LEN` is constant `usize` expression, same as array size
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::{const_arr, const_subarr};
+/// 
+/// const SQUARES: [u32; 16] = const_arr!([u32; 16], |i| (i * i) as u32);
+/// 
+/// const FIRST: [u32; 4] = const_subarr!(SQUARES, 0, 4);
+/// assert_eq!(FIRST, [0, 1, 4, 9]);
+/// 
+/// const LAST: [u32; 2] = const_subarr!(SQUARES, 14, 2);
+/// assert_eq!(LAST, [196, 225]);
+/// 
+/// const EMPTY: [u32; 0] = const_subarr!(SQUARES, 16, 0);
+/// assert_eq!(EMPTY, []);
+/// ```
+/// 
+/// ### Panics
+/// Sub-array out of bounds of `SRC` aborts compilation with const evaluation error.
+/// ```compile_fail,E0080
+/// use const_array_init::{const_arr, const_subarr};
+/// 
+/// const SQUARES: [u32; 16] = const_arr!([u32; 16], |i| (i * i) as u32);
+/// 
+/// const DST: [u32; 4] = const_subarr!(SQUARES, 14, 4);
+/// # let _ = DST;
+/// ```
+/// 
+/// Sub-array is seeded with item of `SRC`, so empty `SRC` needs literal `0` as `LEN`,
+/// which is handled without seed. Constant that equals `0` isn't enough:
+/// ```compile_fail,E0080
+/// use const_array_init::const_subarr;
+/// 
+/// const NONE: [u32; 0] = [];
+/// const LEN: usize = 0;
+/// 
+/// const DST: [u32; LEN] = const_subarr!(NONE, 0, LEN);
+/// //                      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ const_subarr!: sub-array of empty source array needs literal 0 as LEN
+/// # let _ = DST;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_subarr {
+    // Empty sub-array needs no seed, so source isn't indexed at all.
+    ($SRC:expr, $START:expr, 0 $(,)?) => {
+        {
+            if $START > $SRC.len() {
+                panic!("const_subarr!: sub-array is out of bounds of source array");
+            }
+            []
+        }
+    };
+    ($SRC:expr, $START:expr, $LEN:expr $(,)?) => {
+        {
+            if $START + $LEN > $SRC.len() {
+                panic!("const_subarr!: sub-array is out of bounds of source array");
+            }
+            // Item type is unknown, so seed cannot be a constant and
+            // is repeated as a value. That's why it has to be `Copy`.
+            // Repeated value is computed even for empty array, and empty
+            // source has no item to be used as seed.
+            if $SRC.len() == 0 {
+                panic!("const_subarr!: sub-array of empty source array needs literal 0 as LEN");
+            }
+            // Empty sub-array may start at the end of source, so seed
+            // is taken from index 0 then.
+            let mut arr = [$SRC[if $LEN == 0 { 0 } else { $START }]; $LEN];
+
+            let mut ind = 1;
+            while ind < arr.len() {
+                arr[ind] = $SRC[$START + ind];
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify source array SRC: \n      const DST: [TYPE; LEN] = const_subarr!(SRC, START, LEN);\n e.g. const DST: [i32;  4  ] = const_subarr!(SRC, 2, 4);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be SRC, START, LEN: \n      const DST: [TYPE; LEN] = const_subarr!(SRC, START, LEN);\n e.g. const DST: [i32;  4  ] = const_subarr!(SRC, 2, 4);\n got:  const_subarr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to concatenate two constant arrays in constant context
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_concat_arr!([TYPE; SIZE], LEFT, RIGHT);
+/// ```
+/// 
+/// - `LEFT` and `RIGHT` are constant arrays or slices of `Copy` items
+/// - `SIZE` is constant `usize` expression equal to `LEFT.len() + RIGHT.len()`,
+///   e.g. `A + B` where `A` and `B` are lengths of `LEFT` and `RIGHT`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_concat_arr;
+/// 
+/// const LEFT: [u8; 2] = [1, 2];
+/// const RIGHT: [u8; 3] = [3, 4, 5];
+/// 
+/// const ARR: [u8; 5] = const_concat_arr!([u8; 2 + 3], LEFT, RIGHT);
+/// assert_eq!(ARR, [1, 2, 3, 4, 5]);
+/// ```
+/// 
+/// ### Panics
+/// `SIZE` that isn't sum of lengths aborts compilation with const evaluation error.
+/// Both arrays can't be empty, because there is no item to create array from.
+/// ```compile_fail,E0080
+/// use const_array_init::const_concat_arr;
+/// 
+/// const LEFT: [u8; 2] = [1, 2];
+/// const RIGHT: [u8; 3] = [3, 4, 5];
+/// 
+/// const ARR: [u8; 4] = const_concat_arr!([u8; 4], LEFT, RIGHT);
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_concat_arr {
+    ([$TYPE:ty; $SIZE:expr], $LEFT:expr, $RIGHT:expr $(,)?) => {
+        {
+            let left_len = $LEFT.len();
+            if left_len + $RIGHT.len() != $SIZE {
+                panic!("const_concat_arr!: array size must be sum of LEFT and RIGHT lengths");
+            }
+            // Same as in `const_subarr!`, seed is repeated as a value, so items are `Copy`.
+            // LEFT may be empty, then first item of RIGHT is used.
+            let seed: $TYPE = if left_len > 0 {
+                $LEFT[0]
+            } else if $RIGHT.len() > 0 {
+                $RIGHT[0]
+            } else {
+                panic!("const_concat_arr!: LEFT and RIGHT are both empty")
+            };
+            let mut arr: [$TYPE; $SIZE] = [seed; $SIZE];
+
+            let mut ind = 0;
+            while ind < left_len {
+                arr[ind] = $LEFT[ind];
+                ind += 1;
+            }
+            let mut ind = 0;
+            while ind < $RIGHT.len() {
+                arr[left_len + ind] = $RIGHT[ind];
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_concat_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  5   ] = const_concat_arr!([i32;  2 + 3], LEFT, RIGHT);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify arrays LEFT and RIGHT: \n      const ARR: [TYPE; SIZE] = const_concat_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  5   ] = const_concat_arr!([i32;  2 + 3], LEFT, RIGHT);"); };
+    ([$type:ty; $size:expr], $left:expr $(,)?) => {compile_error!("Please specify array RIGHT: \n      const ARR: [TYPE; SIZE] = const_concat_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  5   ] = const_concat_arr!([i32;  2 + 3], LEFT, RIGHT);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_concat_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  5   ] = const_concat_arr!([i32;  2 + 3], LEFT, RIGHT);\n got:  const_concat_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to interleave items of two constant arrays in constant context
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_interleave_arr!([TYPE; SIZE], LEFT, RIGHT);
+/// ```
+/// 
+/// - `LEFT` and `RIGHT` are constant arrays or slices of `Copy` items with same length
+/// - `SIZE` is constant `usize` expression equal to `2 * LEFT.len()`
+/// - Array is `[LEFT[0], RIGHT[0], LEFT[1], RIGHT[1], ...]`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_interleave_arr;
+/// 
+/// const LEFT_CHANNEL: [i16; 2] = [1, 2];
+/// const RIGHT_CHANNEL: [i16; 2] = [3, 4];
+/// 
+/// const STEREO: [i16; 4] = const_interleave_arr!([i16; 2 * 2], LEFT_CHANNEL, RIGHT_CHANNEL);
+/// assert_eq!(STEREO, [1, 3, 2, 4]);
+/// ```
+/// 
+/// ### Panics
+/// Arrays with different length or `SIZE` that isn't sum of their lengths abort
+/// compilation with const evaluation error. Both arrays can't be empty, because there
+/// is no item to create array from.
+/// ```compile_fail,E0080
+/// use const_array_init::const_interleave_arr;
+/// 
+/// const LEFT: [i16; 2] = [1, 2];
+/// const RIGHT: [i16; 3] = [3, 4, 5];
+/// 
+/// const ARR: [i16; 5] = const_interleave_arr!([i16; 5], LEFT, RIGHT);
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_interleave_arr {
+    ([$TYPE:ty; $SIZE:expr], $LEFT:expr, $RIGHT:expr $(,)?) => {
+        {
+            let len = $LEFT.len();
+            if len != $RIGHT.len() {
+                panic!("const_interleave_arr!: LEFT and RIGHT have different length");
+            }
+            if 2 * len != $SIZE {
+                panic!("const_interleave_arr!: array size must be sum of LEFT and RIGHT lengths");
+            }
+            if len == 0 {
+                panic!("const_interleave_arr!: LEFT and RIGHT are both empty");
+            }
+            // Same as in `const_concat_arr!`, seed is repeated as a value, so items are `Copy`.
+            let mut arr: [$TYPE; $SIZE] = [$LEFT[0]; $SIZE];
+
+            // Even indices are taken from LEFT and odd ones from RIGHT.
+            let mut ind = 1;
+            while ind < arr.len() {
+                arr[ind] = if ind % 2 == 0 { $LEFT[ind / 2] } else { $RIGHT[ind / 2] };
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_interleave_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  6   ] = const_interleave_arr!([i32;  2 * 3], LEFT, RIGHT);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify arrays LEFT and RIGHT: \n      const ARR: [TYPE; SIZE] = const_interleave_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  6   ] = const_interleave_arr!([i32;  2 * 3], LEFT, RIGHT);"); };
+    ([$type:ty; $size:expr], $left:expr $(,)?) => {compile_error!("Please specify array RIGHT: \n      const ARR: [TYPE; SIZE] = const_interleave_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  6   ] = const_interleave_arr!([i32;  2 * 3], LEFT, RIGHT);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_interleave_arr!([TYPE; SIZE], LEFT, RIGHT);\n e.g. const ARR: [i32;  6   ] = const_interleave_arr!([i32;  2 * 3], LEFT, RIGHT);\n got:  const_interleave_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to zip two constant arrays into a new one in constant context
+/// 
+/// Usage:
+/// ```ignore
+/// const DST: [TYPE; SIZE] = const_zip_arr!(LEFT, RIGHT, |a, b| BODY);
+/// const DST: [TYPE; SIZE] = const_zip_arr!([TYPE; SIZE], LEFT, RIGHT, |a, b| BODY);
+/// ```
+/// 
+/// - `LEFT` and `RIGHT` are constant arrays of the same length. Their items are
+///   copied into `a` and `b`, so they must be `Copy`
+/// - Without `[TYPE; SIZE]` item type is inferred from `BODY` and must be `Copy`
+/// - With `[TYPE; SIZE]` item type can be any type, `SIZE` must be equal to `LEFT.len()`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_zip_arr;
+/// 
+/// const IDS: [u32; 3] = [1, 2, 3];
+/// const NAMES: [&str; 3] = ["one", "two", "three"];
+/// 
+/// const PAIRS: [(u32, &str); 3] = const_zip_arr!(IDS, NAMES, |id, name| (id, name));
+/// assert_eq!(PAIRS, [(1, "one"), (2, "two"), (3, "three")]);
+/// ```
+/// 
+/// Arrays of different length are a compile error:
+/// ```compile_fail
+/// use const_array_init::const_zip_arr;
+/// 
+/// const LEFT: [u32; 3] = [1, 2, 3];
+/// const RIGHT: [u32; 2] = [1, 2];
+/// 
+/// const SUMS: [u32; 3] = const_zip_arr!(LEFT, RIGHT, |a, b| a + b);
+/// # let _ = SUMS;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_zip_arr {
+    ([$TYPE:ty; $SIZE:expr], $LEFT:expr, $RIGHT:expr, |$a:ident, $b:ident| $body:expr $(,)?) => {
+        {
+            const __TEMP_ITEM: $TYPE = {
+                let $a = $LEFT[0];
+                let $b = $RIGHT[0];
+                $body
+            };
+            let mut arr: [$TYPE; $SIZE] = [__TEMP_ITEM; $SIZE];
+            if arr.len() != $LEFT.len() || arr.len() != $RIGHT.len() {
+                panic!("const_zip_arr!: SIZE is not equal to length of source arrays");
+            }
+
+            // Index 0 already holds zipped LEFT[0] and RIGHT[0] from __TEMP_ITEM, so start from 1.
+            let mut ind = 1;
+            while ind < arr.len() {
+                let $a = $LEFT[ind];
+                let $b = $RIGHT[ind];
+                arr[ind] = $body;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    ($LEFT:expr, $RIGHT:expr, |$a:ident, $b:ident| $body:expr $(,)?) => {
+        {
+            if $LEFT.len() != $RIGHT.len() {
+                panic!("const_zip_arr!: source arrays have different length");
+            }
+
+            // Item type is unknown, so seed cannot be a constant and
+            // is repeated as a value. That's why it has to be `Copy`.
+            let mut arr = {
+                let $a = $LEFT[0];
+                let $b = $RIGHT[0];
+                [$body; $LEFT.len()]
+            };
+
+            let mut ind = 1;
+            while ind < arr.len() {
+                let $a = $LEFT[ind];
+                let $b = $RIGHT[ind];
+                arr[ind] = $body;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify source arrays LEFT and RIGHT: \n      const DST: [TYPE; SIZE] = const_zip_arr!(LEFT, RIGHT, ZIP_FN);\n e.g. const DST: [i32;  10  ] = const_zip_arr!(LEFT, RIGHT, |a, b| a + b);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Zip function has wrong format. It should be |a, b| (a, b): \n      const DST: [TYPE; SIZE] = const_zip_arr!(LEFT, RIGHT, ZIP_FN);\n e.g. const DST: [i32;  10  ] = const_zip_arr!(LEFT, RIGHT, |a, b| a + b);\n got:  const_zip_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to initialize two arrays from one init function returning a pair
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const PAIR: ([LEFT; SIZE], [RIGHT; SIZE]) = const_arr_unzip!([LEFT; SIZE], [RIGHT; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - `CONST_INIT_FN` is const function or const-like closure from 
+///   `array index`(`usize`) to `(LEFT, RIGHT)`
+/// - Both arrays are filled in one loop, so init function is called once per index
+/// - Both `SIZE` must be equal
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_unzip;
+/// 
+/// const DIV_MOD: ([u8; 5], [u8; 5]) = const_arr_unzip!([u8; 5], [u8; 5], |i| ((i / 2) as u8, (i % 2) as u8));
+/// assert_eq!(DIV_MOD.0, [0, 0, 1, 1, 2]);
+/// assert_eq!(DIV_MOD.1, [0, 1, 0, 1, 0]);
+/// ```
+/// 
+/// ### Panics
+/// Different `SIZE` of arrays aborts compilation with const evaluation error.
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_unzip;
+/// 
+/// const PAIR: ([u8; 3], [u8; 4]) = const_arr_unzip!([u8; 3], [u8; 4], |i| (i as u8, i as u8));
+/// # let _ = PAIR;
+/// ```
+/// 
+/// Items must not implement `Drop`, because pair is moved apart in const context.
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_unzip {
+    ([$LEFT:ty; $LEFT_SIZE:expr], [$RIGHT:ty; $RIGHT_SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_unzip!([$LEFT; $LEFT_SIZE], [$RIGHT; $RIGHT_SIZE], |ind| $func_path(ind))
+    };
+    ([$LEFT:ty; $LEFT_SIZE:expr], [$RIGHT:ty; $RIGHT_SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`. Seed pair is
+            // split into two constants, so both of them can be repeated.
+            const __TEMP_PAIR: ($LEFT, $RIGHT) = {
+                #[allow(unused_variables)]
+                let $name: usize = 0;
+                $body
+            };
+            const __TEMP_LEFT: $LEFT = __TEMP_PAIR.0;
+            const __TEMP_RIGHT: $RIGHT = __TEMP_PAIR.1;
+            let mut left: [$LEFT; $LEFT_SIZE] = [__TEMP_LEFT; $LEFT_SIZE];
+            let mut right: [$RIGHT; $RIGHT_SIZE] = [__TEMP_RIGHT; $RIGHT_SIZE];
+            if left.len() != right.len() {
+                panic!("const_arr_unzip!: LEFT and RIGHT arrays have different size");
+            }
+
+            let mut $name = 1;
+            while $name < left.len() {
+                let pair: ($LEFT, $RIGHT) = $body;
+                left[$name] = pair.0;
+                right[$name] = pair.1;
+                $name += 1;
+            }
+            (left, right)
+        }
+    };
+    () => {compile_error!("Please specify array types LEFT and RIGHT: \n      const PAIR: ([LEFT; SIZE], [RIGHT; SIZE]) = const_arr_unzip!([LEFT; SIZE], [RIGHT; SIZE], INIT_FN);\n e.g. const PAIR: ([i32;  10  ], [u8;   10  ]) = const_arr_unzip!([i32;  10  ], [u8;   10  ], |i| (i as i32, i as u8));"); };
+    ([$left:ty; $left_size:expr] $(,)?) => {compile_error!("Please specify array type RIGHT: \n      const PAIR: ([LEFT; SIZE], [RIGHT; SIZE]) = const_arr_unzip!([LEFT; SIZE], [RIGHT; SIZE], INIT_FN);\n e.g. const PAIR: ([i32;  10  ], [u8;   10  ]) = const_arr_unzip!([i32;  10  ], [u8;   10  ], |i| (i as i32, i as u8));"); };
+    ([$left:ty; $left_size:expr], [$right:ty; $right_size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const PAIR: ([LEFT; SIZE], [RIGHT; SIZE]) = const_arr_unzip!([LEFT; SIZE], [RIGHT; SIZE], INIT_FN);\n e.g. const PAIR: ([i32;  10  ], [u8;   10  ]) = const_arr_unzip!([i32;  10  ], [u8;   10  ], |i| (i as i32, i as u8));"); };
+    ([$left:ty; $left_size:expr], [$right:ty; $right_size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| (i, i): \n      const PAIR: ([LEFT; SIZE], [RIGHT; SIZE]) = const_arr_unzip!([LEFT; SIZE], [RIGHT; SIZE], INIT_FN);\n e.g. const PAIR: ([i32;  10  ], [u8;   10  ]) = const_arr_unzip!([i32;  10  ], [u8;   10  ], |i| (i as i32, i as u8));\n got:  const_arr_unzip!(", stringify!([$left; $left_size], [$right; $right_size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [LEFT; SIZE], [RIGHT; SIZE]: \n      const PAIR: ([LEFT; SIZE], [RIGHT; SIZE]) = const_arr_unzip!([LEFT; SIZE], [RIGHT; SIZE], INIT_FN);\n e.g. const PAIR: ([i32;  10  ], [u8;   10  ]) = const_arr_unzip!([i32;  10  ], [u8;   10  ], |i| (i as i32, i as u8));\n got:  const_arr_unzip!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but value at index 0 is specified explicitly.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_seeded!([TYPE; SIZE], SEED, CONST_INIT_FN);
+/// ```
+/// 
+/// - `SEED` is constant expression of `TYPE`. It is used for index 0
+/// - `CONST_INIT_FN` is called only for indices starting from 1, so it
+///   doesn't have to be valid for index 0
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_seeded;
+/// 
+/// const INV: [usize; 4] = const_arr_seeded!([usize; 4], 0, |i| 100 / i);
+/// assert_eq!(INV, [0, 100, 50, 33]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_seeded {
+    ([$TYPE:ty; $SIZE:expr], $SEED:expr, $func_path:path $(,)?) => {
+        $crate::const_arr_seeded!([$TYPE; $SIZE], $SEED, |ind| $func_path(ind))
+    };
+    ([$TYPE:ty; $SIZE:expr], $SEED:expr, |$name:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`,
+            // but seed is provided by user and is kept at index 0.
+            const __TEMP_ITEM: $TYPE = $SEED;
+            let mut arr: [$TYPE; $SIZE] = [__TEMP_ITEM; $SIZE];
+
+            let mut $name = 1;
+            while $name < arr.len() {
+                arr[$name] = $body;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_seeded!([TYPE; SIZE], SEED, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_seeded!([i32;  10  ], 0, |i| 100 / i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify SEED and init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_seeded!([TYPE; SIZE], SEED, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_seeded!([i32;  10  ], 0, |i| 100 / i as i32);"); };
+    ([$type:ty; $size:expr], $seed:expr $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_seeded!([TYPE; SIZE], SEED, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_seeded!([i32;  10  ], 0, |i| 100 / i as i32);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be [TYPE; SIZE], SEED, |i| i: \n      const ARR: [TYPE; SIZE] = const_arr_seeded!([TYPE; SIZE], SEED, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_seeded!([i32;  10  ], 0, |i| 100 / i as i32);\n got:  const_arr_seeded!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but for `Copy` types. Works with generic `TYPE`.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_generic!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// [`const_arr`] computes value for index 0 in a separate `const` item to fill the
+/// array with it. Such item cannot use generic parameters and arguments of
+/// enclosing function. This macro copies value for index 0 instead, so it can be
+/// used inside generic `const fn`, but `TYPE` must be `Copy`.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_generic;
+/// 
+/// const fn ramp<const N: usize>(low: char, high: char) -> [char; N] {
+///     const_arr_generic!([char; N], |i| if i < N / 2 { low } else { high })
+/// }
+/// 
+/// const ARR: [char; 4] = ramp('a', 'z');
+/// assert_eq!(ARR, ['a', 'a', 'z', 'z']);
+/// ```
+/// 
+/// Generic `TYPE` needs `T: Copy` bound, and trait bounds on `const fn` are allowed
+/// since Rust 1.61. Regular `fn` can have it on any version:
+/// ```
+/// use const_array_init::const_arr_generic;
+/// 
+/// fn ramp<T: Copy, const N: usize>(low: T, high: T) -> [T; N] {
+///     const_arr_generic!([T; N], |i| if i < N / 2 { low } else { high })
+/// }
+/// 
+/// let arr: [&str; 4] = ramp("low", "high");
+/// assert_eq!(arr, ["low", "low", "high", "high"]);
+/// ```
+/// 
+/// 2D arrays with `|row, col|` closure are supported too, so both dimensions can be generic:
+/// ```
+/// use const_array_init::const_arr_generic;
+/// 
+/// const fn identity<const R: usize, const C: usize>() -> [[u8; C]; R] {
+///     const_arr_generic!([[u8; C]; R], |row, col| (row == col) as u8)
+/// }
+/// 
+/// const ARR: [[u8; 3]; 2] = identity();
+/// assert_eq!(ARR, [[1, 0, 0], [0, 1, 0]]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_generic {
+    ([[$TYPE:ty; $COLS:expr]; $ROWS:expr], |$row:ident, $col:ident| $body:expr $(,)?) => {
+        {
+            // Same as `const_arr_2d!`, but seed row is made of copied seed value.
+            let mut arr: [[$TYPE; $COLS]; $ROWS] = {
+                #[allow(unused_variables)]
+                let $row: usize = 0;
+                #[allow(unused_variables)]
+                let $col: usize = 0;
+                let seed: $TYPE = $body;
+                [[seed; $COLS]; $ROWS]
+            };
+
+            let mut $row = 0;
+            while $row < arr.len() {
+                let mut $col = if $row == 0 { 1 } else { 0 };
+                while $col < arr[$row].len() {
+                    arr[$row][$col] = $body;
+                    $col += 1;
+                }
+                $row += 1;
+            }
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_generic!([$TYPE; $SIZE], |ind| $func_path(ind))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`, but seed
+            // is a copied value instead of a constant item.
+            let mut arr: [$TYPE; $SIZE] = {
+                #[allow(unused_variables)]
+                let $name: usize = 0;
+                let seed: $TYPE = $body;
+                [seed; $SIZE]
+            };
+
+            let mut $name = 1;
+            while $name < arr.len() {
+                arr[$name] = $body;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_generic!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_generic!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_generic!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_generic!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr_generic!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_generic!([i32;  10  ], |i| i as i32);\n got:  const_arr_generic!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_generic!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_generic!([i32;  10  ], |i| i as i32);\n got:  const_arr_generic!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to initialize arrays in constant context from a range of integers
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_from_iter!([TYPE; SIZE], START..END, |x| BODY);
+/// ```
+/// 
+/// - `START..END` is exclusive range of integers of any type, `END - START` must be equal to `SIZE`
+/// - `x` takes values `START`, `START + 1`, ... `END - 1`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_from_iter;
+/// 
+/// const ARR: [i32; 5] = const_arr_from_iter!([i32; 5], -2..3, |x| x * 10);
+/// assert_eq!(ARR, [-20, -10, 0, 10, 20]);
+/// ```
+/// 
+/// Range of wrong length is a compile error:
+/// ```compile_fail
+/// use const_array_init::const_arr_from_iter;
+/// 
+/// const ARR: [i32; 5] = const_arr_from_iter!([i32; 5], 0..4, |x| x);
+/// # let _ = ARR;
+/// ```
+/// 
+/// Length is checked before any item past `START` is computed, so range that is too short
+/// doesn't overflow its type:
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_from_iter;
+/// 
+/// const ARR: [u8; 10] = const_arr_from_iter!([u8; 10], 250u8..255, |x| x);
+/// //                    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ const_arr_from_iter!: length of range is not equal to SIZE
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_from_iter {
+    ([$TYPE:ty; $SIZE:expr], $range:expr, |$x:ident| $body:expr $(,)?) => {
+        {
+            const __TEMP_ITEM: $TYPE = {
+                let $x = $range.start;
+                $body
+            };
+            let mut arr: [$TYPE; $SIZE] = [__TEMP_ITEM; $SIZE];
+
+            // Length is checked before the loop, so `last` never goes past END.
+            // Range type is unknown, so bounds are compared as `i128`, and difference
+            // wraps, so it is also correct for `u128` bounds over `i128::MAX`.
+            let range = $range;
+            if (range.end as i128).wrapping_sub(range.start as i128) != arr.len() as i128 {
+                panic!("const_arr_from_iter!: length of range is not equal to SIZE");
+            }
+
+            // Index 0 already holds value for START from __TEMP_ITEM, so start from 1.
+            let mut last = range.start;
+            let mut ind = 1;
+            while ind < arr.len() {
+                last += 1;
+                let $x = last;
+                arr[ind] = $body;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_from_iter!([TYPE; SIZE], START..END, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_from_iter!([i32;  10  ], -5..5, |x| x as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify range START..END and init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_from_iter!([TYPE; SIZE], START..END, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_from_iter!([i32;  10  ], -5..5, |x| x as i32);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be [TYPE; SIZE], START..END, |x| x: \n      const ARR: [TYPE; SIZE] = const_arr_from_iter!([TYPE; SIZE], START..END, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_from_iter!([i32;  10  ], -5..5, |x| x as i32);\n got:  const_arr_from_iter!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but init function also takes array length.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_with_len!([TYPE; SIZE], |i, len| BODY);
+/// const ARR: [TYPE; SIZE] = const_arr_with_len!([TYPE; SIZE], CONST_FN);
+/// ```
+/// 
+/// - `len` is equal to array length `SIZE`
+/// - `CONST_FN` is path to `const fn(usize, usize) -> TYPE` called with `(index, len)`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_with_len;
+/// 
+/// const RAMP: [f32; 4] = const_arr_with_len!([f32; 4], |i, len| i as f32 / len as f32);
+/// assert_eq!(RAMP, [0.0, 0.25, 0.5, 0.75]);
+/// ```
+/// 
+/// Same with `const fn`:
+/// ```
+/// use const_array_init::const_arr_with_len;
+/// 
+/// const fn percent(i: usize, len: usize) -> u8 {
+///     (i * 100 / len) as u8
+/// }
+/// 
+/// const PERCENT: [u8; 4] = const_arr_with_len!([u8; 4], percent);
+/// assert_eq!(PERCENT, [0, 25, 50, 75]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_with_len {
+    ([$TYPE:ty; $SIZE:expr], |$name:ident, $len:ident| $body:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE], |$name| {
+            let $len: usize = $SIZE;
+            $body
+        })
+    };
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE], |ind| $func_path(ind, $SIZE))
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_with_len!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [f32;  10  ] = const_arr_with_len!([f32;  10  ], |i, len| i as f32 / len as f32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_with_len!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [f32;  10  ] = const_arr_with_len!([f32;  10  ], |i, len| i as f32 / len as f32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i, len| i or path to const fn: \n      const ARR: [TYPE; SIZE] = const_arr_with_len!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [f32;  10  ] = const_arr_with_len!([f32;  10  ], |i, len| i as f32 / len as f32);\n got:  const_arr_with_len!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_with_len!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [f32;  10  ] = const_arr_with_len!([f32;  10  ], |i, len| i as f32 / len as f32);\n got:  const_arr_with_len!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but passes accumulator from one item to the next.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_scan!([TYPE; SIZE], INIT_ACC, |i, acc| (ITEM, NEXT_ACC));
+/// ```
+/// 
+/// - `INIT_ACC` is constant accumulator value for index 0
+/// - Closure returns tuple of item at index `i` and accumulator for index `i + 1`
+/// - `TYPE` must be `Copy`, because item for index 0 is used as seed for the whole array
+/// - Accumulator type must not implement `Drop`, because it is dropped in const context
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_scan;
+/// 
+/// const FIB: [u64; 10] = const_arr_scan!([u64; 10], (0, 1), |i, acc| (acc.0, (acc.1, acc.0 + acc.1)));
+/// assert_eq!(FIB, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_scan {
+    ([$TYPE:ty; $SIZE:expr], $INIT:expr, |$name:ident, $acc:ident| $body:expr $(,)?) => {
+        {
+            // Accumulator type is unknown, so seed cannot be a constant and item
+            // for index 0 is repeated as a value. That's why it has to be `Copy`.
+            // Seed also gives accumulator for index 1, so loop starts from 1.
+            let (item, mut acc) = {
+                #[allow(unused_variables)]
+                let $name: usize = 0;
+                let $acc = $INIT;
+                $body
+            };
+            let mut arr: [$TYPE; $SIZE] = [item; $SIZE];
+
+            let len = arr.len();
+            let mut ind = 1;
+            while ind < len {
+                let (item, next) = {
+                    #[allow(unused_variables)]
+                    let $name: usize = ind;
+                    let $acc = acc;
+                    $body
+                };
+                arr[ind] = item;
+                acc = next;
+                ind += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_scan!([TYPE; SIZE], INIT_ACC, |i, acc| (ITEM, NEXT_ACC));\n e.g. const ARR: [u32;  10  ] = const_arr_scan!([u32;  10  ], 1, |i, acc| (acc, acc * 2));"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify accumulator INIT_ACC and init function: \n      const ARR: [TYPE; SIZE] = const_arr_scan!([TYPE; SIZE], INIT_ACC, |i, acc| (ITEM, NEXT_ACC));\n e.g. const ARR: [u32;  10  ] = const_arr_scan!([u32;  10  ], 1, |i, acc| (acc, acc * 2));"); };
+    ([$type:ty; $size:expr], $init:expr $(,)?) => {compile_error!("Please specify init function |i, acc| (ITEM, NEXT_ACC): \n      const ARR: [TYPE; SIZE] = const_arr_scan!([TYPE; SIZE], INIT_ACC, |i, acc| (ITEM, NEXT_ACC));\n e.g. const ARR: [u32;  10  ] = const_arr_scan!([u32;  10  ], 1, |i, acc| (acc, acc * 2));"); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be [TYPE; SIZE], INIT_ACC, |i, acc| (ITEM, NEXT_ACC): \n      const ARR: [TYPE; SIZE] = const_arr_scan!([TYPE; SIZE], INIT_ACC, |i, acc| (ITEM, NEXT_ACC));\n e.g. const ARR: [u32;  10  ] = const_arr_scan!([u32;  10  ], 1, |i, acc| (acc, acc * 2));\n got:  const_arr_scan!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but first items are given and the rest is computed from them.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_recurrence!([TYPE; SIZE], [BASE0, BASE1, ...], |i, arr| BODY);
+/// ```
+/// 
+/// - Items `BASE0, BASE1, ...` are constant expressions used for first `K` indices
+/// - `CONST_INIT_FN` is const function or const-like closure from index and array so far
+///   (`usize`, `&[TYPE]`) to `TYPE`. It is called for indices starting from `K`
+/// - Items before index `i` are already computed, so `arr[i - 1]` ... `arr[i - K]` can be used
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_recurrence;
+/// 
+/// const FIB: [u64; 10] = const_arr_recurrence!([u64; 10], [0, 1], |i, arr| arr[i - 1] + arr[i - 2]);
+/// assert_eq!(FIB, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+/// ```
+/// 
+/// ### Panics
+/// `SIZE` smaller than number of base items aborts compilation with const evaluation error.
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_recurrence;
+/// 
+/// const ARR: [u64; 2] = const_arr_recurrence!([u64; 2], [1, 1, 2], |i, arr| arr[i - 1] + arr[i - 2]);
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_recurrence {
+    ([$TYPE:ty; $SIZE:expr], [$($BASE:expr),+ $(,)?], $func_path:path $(,)?) => {
+        $crate::const_arr_recurrence!([$TYPE; $SIZE], [$($BASE),+], |ind, arr| $func_path(ind, arr))
+    };
+    ([$TYPE:ty; $SIZE:expr], [$FIRST:expr $(, $BASE:expr)* $(,)?], |$name:ident, $arr:ident| $body:expr $(,)?) => {
+        {
+            // Same seed-then-overwrite approach as in `const_arr!`, but seed is
+            // first base item. Other base items are copied right after it.
+            const __TEMP_ITEM: $TYPE = $FIRST;
+            let mut arr: [$TYPE; $SIZE] = [__TEMP_ITEM; $SIZE];
+            if arr.len() == 0 {
+                panic!("const_arr_recurrence!: array is shorter than base items");
+            }
+
+            let len = arr.len();
+            let mut ind = 1;
+            $(
+                if ind == len {
+                    panic!("const_arr_recurrence!: array is shorter than base items");
+                }
+                arr[ind] = $BASE;
+                ind += 1;
+            )*
+            // $arr is bound to read-only slice view of the array, same as in `const_arr!`.
+            let mut $name = ind;
+            while $name < len {
+                let item = {
+                    #[allow(unused_variables)]
+                    let $arr: &[$TYPE] = &arr;
+                    $body
+                };
+                arr[$name] = item;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_recurrence!([TYPE; SIZE], [BASE0, BASE1, ...], INIT_FN);\n e.g. const ARR: [u64;  10  ] = const_arr_recurrence!([u64;  10  ], [0, 1], |i, arr| arr[i - 1] + arr[i - 2]);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify base items [BASE0, BASE1, ...] and init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_recurrence!([TYPE; SIZE], [BASE0, BASE1, ...], INIT_FN);\n e.g. const ARR: [u64;  10  ] = const_arr_recurrence!([u64;  10  ], [0, 1], |i, arr| arr[i - 1] + arr[i - 2]);"); };
+    ([$type:ty; $size:expr], [$($base:expr),* $(,)?] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_recurrence!([TYPE; SIZE], [BASE0, BASE1, ...], INIT_FN);\n e.g. const ARR: [u64;  10  ] = const_arr_recurrence!([u64;  10  ], [0, 1], |i, arr| arr[i - 1] + arr[i - 2]);"); };
+    ([$type:ty; $size:expr], [$($base:expr),* $(,)?], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i, arr| arr[i - 1]: \n      const ARR: [TYPE; SIZE] = const_arr_recurrence!([TYPE; SIZE], [BASE0, BASE1, ...], INIT_FN);\n e.g. const ARR: [u64;  10  ] = const_arr_recurrence!([u64;  10  ], [0, 1], |i, arr| arr[i - 1] + arr[i - 2]);\n got:  const_arr_recurrence!(", stringify!([$type; $size], [$($base),*], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be [TYPE; SIZE], [BASE0, BASE1, ...], |i, arr| arr[i - 1]: \n      const ARR: [TYPE; SIZE] = const_arr_recurrence!([TYPE; SIZE], [BASE0, BASE1, ...], INIT_FN);\n e.g. const ARR: [u64;  10  ] = const_arr_recurrence!([u64;  10  ], [0, 1], |i, arr| arr[i - 1] + arr[i - 2]);\n got:  const_arr_recurrence!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but checks finished array with predicate.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const RES: Result<[TYPE; SIZE], ValidationError> = const_arr_validated!([TYPE; SIZE], CONST_INIT_FN, |arr| PREDICATE);
+/// ```
+/// 
+/// - `PREDICATE` is `bool` expression evaluated once, `arr` is reference to finished array
+/// - Returns `Ok(array)` if predicate is `true` and `Err(ValidationError)` otherwise
+/// 
+/// Unlike [`const_arr_try`], items can't fail, but whole array is checked.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_validated;
+/// 
+/// const fn is_sorted(arr: &[u8]) -> bool {
+///     let mut i = 1;
+///     while i < arr.len() {
+///         if arr[i - 1] > arr[i] { return false; }
+///         i += 1;
+///     }
+///     true
+/// }
+/// 
+/// const ARR: [u8; 4] = match const_arr_validated!([u8; 4], |i| (i * i) as u8, |arr| is_sorted(arr)) {
+///     Ok(arr) => arr,
+///     Err(_) => panic!("ARR is not sorted"),
+/// };
+/// assert_eq!(ARR, [0, 1, 4, 9]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_validated {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path, |$arr:ident| $pred:expr $(,)?) => {
+        $crate::const_arr_validated!([$TYPE; $SIZE], |ind| $func_path(ind), |$arr| $pred)
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr, |$arr:ident| $pred:expr $(,)?) => {
+        {
+            let arr: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |$name| $body);
+            let valid: bool = {
+                let $arr: &[$TYPE; $SIZE] = &arr;
+                $pred
+            };
+            if valid {
+                Ok(arr)
+            } else {
+                Err($crate::ValidationError)
+            }
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const RES: Result<[TYPE; SIZE], ValidationError> = const_arr_validated!([TYPE; SIZE], INIT_FN, |arr| PREDICATE);\n e.g. const RES: Result<[i32;  10  ], ValidationError> = const_arr_validated!([i32;  10  ], |i| i as i32, |arr| arr[0] == 0);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN and predicate: \n      const RES: Result<[TYPE; SIZE], ValidationError> = const_arr_validated!([TYPE; SIZE], INIT_FN, |arr| PREDICATE);\n e.g. const RES: Result<[i32;  10  ], ValidationError> = const_arr_validated!([i32;  10  ], |i| i as i32, |arr| arr[0] == 0);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be [TYPE; SIZE], INIT_FN, |arr| PREDICATE: \n      const RES: Result<[TYPE; SIZE], ValidationError> = const_arr_validated!([TYPE; SIZE], INIT_FN, |arr| PREDICATE);\n e.g. const RES: Result<[i32;  10  ], ValidationError> = const_arr_validated!([i32;  10  ], |i| i as i32, |arr| arr[0] == 0);\n got:  const_arr_validated!(", stringify!($($args)*), ")")); };
+}
+
+/// Error returned by [`const_arr_validated`] when predicate is `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationError;
+
+/// ### Same as [`const_arr`] macro, but index is centered around zero.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_centered!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - `CONST_INIT_FN` is const function or const-like closure from 
+///   centered index (`isize`) to `TYPE`
+/// - Centered index takes values `-(SIZE / 2)`, `-(SIZE / 2) + 1`, ... so for odd `SIZE`
+///   middle item has index 0
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_centered;
+/// 
+/// const ARR: [i32; 5] = const_arr_centered!([i32; 5], |x| x as i32);
+/// assert_eq!(ARR, [-2, -1, 0, 1, 2]);
+/// 
+/// const KERNEL: [u32; 5] = const_arr_centered!([u32; 5], |x| 3 - x.unsigned_abs() as u32);
+/// assert_eq!(KERNEL, [1, 2, 3, 2, 1]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_centered {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_centered!([$TYPE; $SIZE], |x| $func_path(x))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE], |ind| {
+            let $name: isize = ind as isize - ($SIZE / 2) as isize;
+            $body
+        })
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_centered!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  11  ] = const_arr_centered!([i32;  11  ], |x| x as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_centered!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  11  ] = const_arr_centered!([i32;  11  ], |x| x as i32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |x| x: \n      const ARR: [TYPE; SIZE] = const_arr_centered!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  11  ] = const_arr_centered!([i32;  11  ], |x| x as i32);\n got:  const_arr_centered!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_centered!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  11  ] = const_arr_centered!([i32;  11  ], |x| x as i32);\n got:  const_arr_centered!(", stringify!($($args)*), ")")); };
+}
 
-/// ### Macro used to initialize arrays in constant context
+/// ### Same as [`const_arr`] macro, but index starts from given offset.
 /// #### Supports both `closure` syntax and `const fn` initialization.
 /// 
 /// Usage:
 /// ```ignore
-/// const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], CONST_INIT_FN);
+/// const ARR: [TYPE; SIZE] = const_arr_offset!([TYPE; SIZE], OFFSET, CONST_INIT_FN);
 /// ```
 /// 
 /// - `CONST_INIT_FN` is const function or const-like closure from 
-/// `array index`(`usize`) to `TYPE`
+///   `OFFSET + array index` (`usize`) to `TYPE`
+/// - `OFFSET` is constant `usize` expression
 /// 
 /// ### Examples:
 /// ```
-/// use const_array_init::const_arr;
+/// use const_array_init::const_arr_offset;
 /// 
-/// const ARR1: [i32; 5] = const_arr!([i32; 5], |i| i as i32 + 1);
-/// assert_eq!(ARR1, [1, 2, 3, 4, 5]);
+/// const ARR: [i32; 3] = const_arr_offset!([i32; 3], 100, |i| i as i32);
+/// assert_eq!(ARR, [100, 101, 102]);
+/// ```
 /// 
-/// const fn to_i32_plus_one(n: usize) -> i32 {
-///     n as i32 + 1
+/// ### Panics
+/// `OFFSET + array index` that overflows `usize` aborts compilation with const evaluation error.
+/// ```compile_fail,E0080
+/// use const_array_init::const_arr_offset;
+/// 
+/// const ARR: [usize; 3] = const_arr_offset!([usize; 3], usize::MAX - 1, |i| i);
+/// # let _ = ARR;
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_offset {
+    ([$TYPE:ty; $SIZE:expr], $OFFSET:expr, $func_path:path $(,)?) => {
+        $crate::const_arr_offset!([$TYPE; $SIZE], $OFFSET, |i| $func_path(i))
+    };
+    ([$TYPE:ty; $SIZE:expr], $OFFSET:expr, |$name:ident| $body:expr $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE], |ind| {
+            let $name: usize = $OFFSET + ind;
+            $body
+        })
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_offset!([TYPE; SIZE], OFFSET, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_offset!([i32;  10  ], 100, |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify offset OFFSET: \n      const ARR: [TYPE; SIZE] = const_arr_offset!([TYPE; SIZE], OFFSET, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_offset!([i32;  10  ], 100, |i| i as i32);"); };
+    ([$type:ty; $size:expr], $offset:expr $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_offset!([TYPE; SIZE], OFFSET, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_offset!([i32;  10  ], 100, |i| i as i32);"); };
+    ([$type:ty; $size:expr], $offset:expr, $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr_offset!([TYPE; SIZE], OFFSET, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_offset!([i32;  10  ], 100, |i| i as i32);\n got:  const_arr_offset!(", stringify!([$type; $size], $offset, $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_offset!([TYPE; SIZE], OFFSET, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_offset!([i32;  10  ], 100, |i| i as i32);\n got:  const_arr_offset!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but seeds array with zeros for numeric types.
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_zeroed_seed!([TYPE; SIZE], CONST_INIT_FN);
+/// const ARR: [TYPE; SIZE] = const_arr_zeroed_seed!([TYPE; SIZE], start = START, CONST_INIT_FN);
+/// ```
+/// 
+/// - `TYPE` is primitive integer or float type, because seed is created with `0 as TYPE`
+/// 
+/// [`const_arr`] evaluates init function for index 0 as separate seed `const` and fills
+/// array with it first. This macro fills it with zeros instead and then overwrites every
+/// item, including index 0. Init function is called only while filling the array, so it isn't
+/// called at all for empty array, and seed doesn't prevent `SIZE` from being generic parameter
+/// of enclosing `const fn`.
+/// 
+/// Init function is still called for index 0. If it isn't valid there, opt in with
+/// `start = START`: items before `START` keep zero seed and init function isn't called for them.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_zeroed_seed;
+/// 
+/// const fn squares<const N: usize>() -> [u32; N] {
+///     const_arr_zeroed_seed!([u32; N], |i| (i * i) as u32)
 /// }
 /// 
-/// const ARR2: [i32; 5] = const_arr!([i32; 5], to_i32_plus_one);
-/// assert_eq!(ARR2, [1, 2, 3, 4, 5]);
+/// const SQUARES: [u32; 4] = squares();
+/// assert_eq!(SQUARES, [0, 1, 4, 9]);
+/// 
+/// const fn inv(n: usize) -> u32 {
+///     100 / n as u32
+/// }
+/// 
+/// const INV: [u32; 5] = const_arr_zeroed_seed!([u32; 5], start = 1, inv);
+/// assert_eq!(INV, [0, 100, 50, 33, 25]);
 /// ```
-/// You have to specify array type in const context, even if compiler can infer it.
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_zeroed_seed {
+    ([$TYPE:ty; $SIZE:expr], start = $START:expr, $func_path:path $(,)?) => {
+        $crate::const_arr_zeroed_seed!([$TYPE; $SIZE], start = $START, |i| $func_path(i))
+    };
+    ([$TYPE:ty; $SIZE:expr], start = $START:expr, |$name:ident| $body:expr $(,)?) => {
+        {
+            let mut arr: [$TYPE; $SIZE] = [0 as $TYPE; $SIZE];
+
+            // Items before START keep zero seed.
+            let len = arr.len();
+            let mut $name = $START;
+            while $name < len {
+                arr[$name] = $body;
+                $name += 1;
+            }
+            arr
+        }
+    };
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_zeroed_seed!([$TYPE; $SIZE], start = 0, |i| $func_path(i))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        $crate::const_arr_zeroed_seed!([$TYPE; $SIZE], start = 0, |$name| $body)
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_zeroed_seed!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_zeroed_seed!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_zeroed_seed!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_zeroed_seed!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr_zeroed_seed!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_zeroed_seed!([i32;  10  ], |i| i as i32);\n got:  const_arr_zeroed_seed!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_zeroed_seed!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_zeroed_seed!([i32;  10  ], |i| i as i32);\n got:  const_arr_zeroed_seed!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr_zeroed_seed`] macro, but item at index 0 always keeps zero seed.
+/// #### Supports both `closure` syntax and `const fn` initialization.
 /// 
-/// This is good `quick-fix` opportunity for your language server.
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_copy!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - `TYPE` is primitive integer or float type, because array is created as `[0 as TYPE; SIZE]`
+/// - Item at index 0 is always `0`. `CONST_INIT_FN` is called only for indices
+///   starting from 1, so it doesn't have to be valid for index 0
+/// - Seed isn't `const` item, so `SIZE` can be generic parameter of enclosing
+///   `const fn`. `TYPE` can't, because `0` can be cast only to primitive type
+/// 
+/// Alias of `const_arr_zeroed_seed!([TYPE; SIZE], start = 1, CONST_INIT_FN)`.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_copy;
+/// 
+/// // Division by zero is undefined, 0 marks missing value
+/// const INV: [u32; 5] = const_arr_copy!([u32; 5], |i| 100 / i as u32);
+/// assert_eq!(INV, [0, 100, 50, 33, 25]);
+/// 
+/// // Logarithm of zero is undefined, 0 marks missing value
+/// const fn log2_table<const N: usize>() -> [u8; N] {
+///     const_arr_copy!([u8; N], |i| (usize::BITS - 1 - i.leading_zeros()) as u8)
+/// }
+/// 
+/// const LOG2: [u8; 8] = log2_table();
+/// assert_eq!(LOG2, [0, 0, 1, 1, 2, 2, 2, 2]);
+/// ```
+/// 
+/// Types which can't be cast from integer literal `0` are rejected:
+/// ```compile_fail,E0606
+/// use const_array_init::const_arr_copy;
+/// 
+/// const ARR: [&str; 3] = const_arr_copy!([&str; 3], |i| "a");
+/// # let _ = ARR;
+/// ```
+/// Generic `TYPE` is rejected for the same reason, even if it is always integer:
+/// ```compile_fail,E0605
+/// use const_array_init::const_arr_copy;
+/// 
+/// const fn ones<T: Copy, const N: usize>(one: T) -> [T; N] {
+///     const_arr_copy!([T; N], |i| one)
+/// }
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_copy {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::const_arr_zeroed_seed!([$TYPE; $SIZE], start = 1, $func_path)
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        $crate::const_arr_zeroed_seed!([$TYPE; $SIZE], start = 1, |$name| $body)
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_copy!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_copy!([i32;  10  ], |i| 100 / i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_copy!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_copy!([i32;  10  ], |i| 100 / i as i32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr_copy!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_copy!([i32;  10  ], |i| 100 / i as i32);\n got:  const_arr_copy!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_copy!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_copy!([i32;  10  ], |i| 100 / i as i32);\n got:  const_arr_copy!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to create array of little-endian bytes of integer in constant context
 /// 
+/// Usage:
 /// ```ignore
-/// const ARR = const_arr!([i32; 5], |i| i as i32);
-/// //    ^^^ help: provide a type for the constant: `: [i32; 5]`
-/// const ARR: [i32; 5] = const_arr!([i32; 5], |i| i as i32);
+/// const BYTES: [u8; SIZE] = const_le_bytes_arr!(VALUE);
 /// ```
 /// 
-/// But if you don't want to specify type twice you can use 
-/// - `make_const_arr!(NAME, [TYPE; SIZE], INIT_FN)` macro.
+/// - `VALUE` is constant expression of primitive integer type
+/// - `SIZE` is inferred from type of `VALUE`, e.g. `4` for `u32`
+/// 
+/// Same as `VALUE.to_le_bytes()`. Single byte can be used in [`const_arr`] init function
+/// as `VALUE.to_le_bytes()[i]`.
 /// 
+/// ### Examples:
 /// ```
-/// use const_array_init::make_const_arr;
+/// use const_array_init::const_le_bytes_arr;
 /// 
-/// make_const_arr!(ARR, [i32; 5], |i| i as i32 + 1);
+/// const MAGIC: u32 = 0x1234_5678;
 /// 
-/// assert_eq!(ARR, [1, 2, 3, 4, 5]);
+/// const BYTES: [u8; 4] = const_le_bytes_arr!(MAGIC);
+/// assert_eq!(BYTES, [0x78, 0x56, 0x34, 0x12]);
 /// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_le_bytes_arr {
+    ($VALUE:expr $(,)?) => {
+        $VALUE.to_le_bytes()
+    };
+    () => {compile_error!("Please specify integer value VALUE: \n      const BYTES: [u8; SIZE] = const_le_bytes_arr!(VALUE);\n e.g. const BYTES: [u8;   4   ] = const_le_bytes_arr!(0x1234_5678_u32);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be single integer VALUE: \n      const BYTES: [u8; SIZE] = const_le_bytes_arr!(VALUE);\n e.g. const BYTES: [u8;   4   ] = const_le_bytes_arr!(0x1234_5678_u32);\n got:  const_le_bytes_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to create array of big-endian bytes of integer in constant context
 /// 
-/// - See [`make_const_arr`]
+/// Usage:
+/// ```ignore
+/// const BYTES: [u8; SIZE] = const_be_bytes_arr!(VALUE);
+/// ```
+/// 
+/// - `VALUE` is constant expression of primitive integer type
+/// - `SIZE` is inferred from type of `VALUE`, e.g. `4` for `u32`
+/// 
+/// Same as `VALUE.to_be_bytes()`. Single byte can be used in [`const_arr`] init function
+/// as `VALUE.to_be_bytes()[i]`.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_be_bytes_arr;
+/// 
+/// const MAGIC: u32 = 0x1234_5678;
+/// 
+/// const BYTES: [u8; 4] = const_be_bytes_arr!(MAGIC);
+/// assert_eq!(BYTES, [0x12, 0x34, 0x56, 0x78]);
+/// ```
 #[macro_export]
 #[rustfmt::skip]
-macro_rules! const_arr {
-    ([$TYPE:ty; $SIZE:literal], $func_name:ident) => {
+macro_rules! const_be_bytes_arr {
+    ($VALUE:expr $(,)?) => {
+        $VALUE.to_be_bytes()
+    };
+    () => {compile_error!("Please specify integer value VALUE: \n      const BYTES: [u8; SIZE] = const_be_bytes_arr!(VALUE);\n e.g. const BYTES: [u8;   4   ] = const_be_bytes_arr!(0x1234_5678_u32);"); };
+    ($($args:tt)*) => {compile_error!(concat!("Wrong format. It should be single integer VALUE: \n      const BYTES: [u8; SIZE] = const_be_bytes_arr!(VALUE);\n e.g. const BYTES: [u8;   4   ] = const_be_bytes_arr!(0x1234_5678_u32);\n got:  const_be_bytes_arr!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Macro used to create array of `None` values in constant context
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [Option<TYPE>; SIZE] = const_arr_none!([Option<TYPE>; SIZE]);
+/// ```
+/// 
+/// - `TYPE` doesn't have to be `Copy`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_none;
+/// 
+/// struct User { id: u32 }
+/// 
+/// const USERS: [Option<User>; 16] = const_arr_none!([Option<User>; 16]);
+/// assert!(USERS.iter().all(Option::is_none));
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_none {
+    ([$TYPE:ty; $SIZE:expr] $(,)?) => {
+        $crate::const_arr!([$TYPE; $SIZE]; None)
+    };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [Option<TYPE>; SIZE]: \n      const ARR: [Option<TYPE>; SIZE] = const_arr_none!([Option<TYPE>; SIZE]);\n e.g. const ARR: [Option<i32>;  10  ] = const_arr_none!([Option<i32>;  10  ]);\n got:  const_arr_none!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but init function returns `Option` and stops at first `None`
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_until!([TYPE; SIZE], DEFAULT, CONST_INIT_FN);
+/// ```
+/// 
+/// - `CONST_INIT_FN` is const function or const-like closure from index to `Option<TYPE>`
+/// - First `None` fills the rest of array with `DEFAULT`, init function isn't called after it
+/// - `DEFAULT` is constant expression of type `TYPE`. It doesn't have to be `Copy`
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_until;
+/// 
+/// const PRIMES: [u32; 8] = const_arr_until!([u32; 8], 0, |i| {
+///     const KNOWN: [u32; 4] = [2, 3, 5, 7];
+///     if i < KNOWN.len() { Some(KNOWN[i]) } else { None }
+/// });
+/// assert_eq!(PRIMES, [2, 3, 5, 7, 0, 0, 0, 0]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_until {
+    ([$TYPE:ty; $SIZE:expr], $DEFAULT:expr, $func_path:path $(, $extra:expr)* $(,)?) => {
         {
-            // Create array of proper SIZE and initialize it with garbage data 
-            // using $func_name(0) call as if every value had index 0.
-            // 
-            // There is no way to create array without initializing it and
-            // we cannot initialize it with 0-s because it isn't always valid (e.g. references)
-            // and MaybeUninit is unsafe and unstable in const context.
-            const TEMP_ITEM: $TYPE = $func_name(0);
-            let mut arr: [$TYPE; $SIZE] = [TEMP_ITEM; $SIZE];
+            // Array is filled with DEFAULT first, so it has to be constant to be repeated.
+            const __DEFAULT: $TYPE = $DEFAULT;
+            let mut arr: [$TYPE; $SIZE] = [__DEFAULT; $SIZE];
 
-            // Initialize array with proper data using $func_name(ind) call
             let mut ind = 0;
-            while ind < $SIZE {
-                arr[ind] = $func_name(ind);
+            while ind < arr.len() {
+                match $func_path(ind $(, $extra)*) {
+                    Some(item) => arr[ind] = item,
+                    None => break,
+                }
                 ind += 1;
             }
             arr
         }
     };
-    ([$TYPE:ty; $SIZE:literal], |$name:ident| $body:expr) => {
+    ([$TYPE:ty; $SIZE:expr], $DEFAULT:expr, |$name:ident| $body:expr $(,)?) => {
         {
-            // Create array of proper SIZE and initialize it with garbage data 
-            // using $body with $name predefined to 0 as if every value had index 0.
-            // 
-            // There is no way to create array without initializing it and
-            // we cannot initialize it with 0-s because it isn't always valid (e.g. references)
-            // and MaybeUninit is is unsafe and unstable in const context.
-            #[allow(non_upper_case_globals)]
-            let mut arr: [$TYPE; $SIZE] = {
-                const $name: usize = 0;
-                const TEMP_ITEM: $TYPE = $body;
-                [TEMP_ITEM; $SIZE]
-            };
+            // Array is filled with DEFAULT first, so it has to be constant to be repeated.
+            const __DEFAULT: $TYPE = $DEFAULT;
+            let mut arr: [$TYPE; $SIZE] = [__DEFAULT; $SIZE];
 
-            // Initialize array with proper data from closure's body
             let mut $name = 0;
-            while $name < $SIZE {
-                arr[$name] = $body;
+            while $name < arr.len() {
+                match $body {
+                    Some(item) => arr[$name] = item,
+                    None => break,
+                }
                 $name += 1;
             }
             arr
         }
     };
-    ([$TYPE:ty; $SIZE:literal], |_| $body:expr ) => {
-        {
-            const TEMP_ITEM: $TYPE = $body;
-            [TEMP_ITEM; $SIZE]
-        }
-    };
-    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ([$type:ty; $size:literal]) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ([$type:ty; $size:literal], ) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ([$type:ty; $size:literal], ||) => {compile_error!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ([$type:ty; $size:literal], || $_wha:tt) => {compile_error!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ([$type:ty; $size:literal], $num:literal) => {compile_error!("Please add |_| to last argument to turn it to closure: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ($type:ty) => {compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ($type:ty, ) => {compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ($type:ty,$size:literal) => {compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ($type:ty,$size:literal, $_:tt) => {compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
-    ($type:ty,$size:literal, |$_:tt| $_n2:tt) => {compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr!([i32;  10  ], |i| i as i32);"); };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_until!([TYPE; SIZE], DEFAULT, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_until!([i32;  10  ], 0, |i| if i < 5 { Some(i as i32) } else { None });"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify default value DEFAULT and init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_until!([TYPE; SIZE], DEFAULT, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_until!([i32;  10  ], 0, |i| if i < 5 { Some(i as i32) } else { None });"); };
+    ([$type:ty; $size:expr], $default:expr $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_until!([TYPE; SIZE], DEFAULT, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_until!([i32;  10  ], 0, |i| if i < 5 { Some(i as i32) } else { None });"); };
+    ([$type:ty; $size:expr], $default:expr, $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| Some(i): \n      const ARR: [TYPE; SIZE] = const_arr_until!([TYPE; SIZE], DEFAULT, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_until!([i32;  10  ], 0, |i| if i < 5 { Some(i as i32) } else { None });\n got:  const_arr_until!(", stringify!([$type; $size], $default, $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_until!([TYPE; SIZE], DEFAULT, INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_until!([i32;  10  ], 0, |i| if i < 5 { Some(i as i32) } else { None });\n got:  const_arr_until!(", stringify!($($args)*), ")")); };
 }
 
-/// ### Wrapper around [`const_arr`] macro. Allows to specify the type of an array `single` time.
-/// #### Supports both `closure` syntax and `const fn` initialization.
+/// ### Macro used to compare constant arrays at compile time
 /// 
 /// Usage:
 /// ```ignore
-/// make_const_arr!(ARR_NAME, [TYPE; SIZE], CONST_INIT_FN);
+/// assert_const_arr_eq!(ARR, [VALUE1, VALUE2, ...]);
 /// ```
 /// 
-/// Desugars to:
-/// ```ignore
-/// const ARR_NAME: [TYPE; SIZE] = const_arr!([TYPE; SIZE], CONST_INIT_FN);
-/// ```
+/// - Both arguments are constant array expressions, e.g. named `const` or array literal
+/// - Elements are compared with `!=` in constant context. Trait methods cannot be
+///   called in const, so only primitive types (integers, `bool`, `char`) can be compared,
+///   derived `PartialEq` doesn't work
 /// 
-/// - `CONST_INIT_FN` is const function or const-like closure from 
-/// `array index`(`usize`) to `TYPE`
+/// ### Examples:
+/// ```
+/// use const_array_init::{assert_const_arr_eq, const_arr};
 /// 
-/// Examples:
+/// const ARR: [i32; 3] = const_arr!([i32; 3], |i| i as i32 + 1);
+/// assert_const_arr_eq!(ARR, [1, 2, 3]);
 /// ```
-/// use const_array_init::make_const_arr;
 /// 
-/// make_const_arr!(ARR1, [i32; 5], |i| i as i32 + 1);
-/// assert_eq!(ARR1, [1, 2, 3, 4, 5]);
+/// ### Panics
+/// Arrays of different length or with different elements abort compilation with const
+/// evaluation error. Const panic messages cannot be formatted, so first differing index
+/// is reported as out of bounds access `the len is 0 but the index is INDEX` right before the panic.
+/// ```compile_fail,E0080
+/// use const_array_init::{assert_const_arr_eq, const_arr};
 /// 
-/// const fn to_i32_plus_one(n: usize) -> i32 {
-///     n as i32 + 1
-/// }
+/// const ARR: [i32; 3] = const_arr!([i32; 3], |i| i as i32 + 1);
+/// assert_const_arr_eq!(ARR, [1, 2, 4]);
+/// // ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ index out of bounds: the length is 0 but the index is 2
+/// ```
+/// ```compile_fail,E0080
+/// use const_array_init::{assert_const_arr_eq, const_arr};
 /// 
-/// make_const_arr!(ARR2, [i32; 5], to_i32_plus_one);
-/// assert_eq!(ARR2, [1, 2, 3, 4, 5]);
+/// const ARR: [i32; 3] = const_arr!([i32; 3], |i| i as i32 + 1);
+/// assert_const_arr_eq!(ARR, [1, 2]);
 /// ```
 #[macro_export]
 #[rustfmt::skip]
-macro_rules! make_const_arr {
-    ($NAME:ident, [$TYPE:ty; $SIZE:literal], $func_name:ident ) => {
-        const $NAME: [$TYPE; $SIZE] = {
-            // Create array of proper SIZE and initialize it with garbage data 
-            // using $func_name(0) call as if every value had index 0.
-            // 
-            // There is no way to create array without initializing it and
-            // we cannot initialize it with 0-s because it isn't always valid (e.g. references)
-            // and MaybeUninit is unsafe and unstable in const context.
-            const TEMP_ITEM: $TYPE = $func_name(0);
-            let mut arr: [$TYPE; $SIZE] = [TEMP_ITEM; $SIZE];
-
-            // Initialize array with proper data using $func_name(ind) call
+macro_rules! assert_const_arr_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        const _: () = {
+            let left = $left;
+            let right = $right;
+            if left.len() != right.len() {
+                panic!("assert_const_arr_eq!: arrays have different length");
+            }
             let mut ind = 0;
-            while ind < $SIZE {
-                arr[ind] = $func_name(ind);
+            while ind < left.len() {
+                if left[ind] != right[ind] {
+                    // Const panic cannot format the index, so report it with
+                    // out of bounds access: "the len is 0 but the index is ind"
+                    let failed_at_index: [usize; 0] = [];
+                    let _index = failed_at_index[ind];
+                    panic!("assert_const_arr_eq!: arrays differ");
+                }
                 ind += 1;
             }
+        };
+    };
+    ($($args:tt)*) => {compile_error!(concat!("Please specify two arrays to compare: \n      assert_const_arr_eq!(ARR, [VALUE1, VALUE2, ...]);\n e.g. assert_const_arr_eq!(ARR, [1, 2, 3]);\n got:  assert_const_arr_eq!(", stringify!($($args)*), ")")); };
+}
+
+/// ### Same as [`const_arr`] macro, but checks built array in debug builds
+/// #### Supports both `closure` syntax and `const fn` initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// const ARR: [TYPE; SIZE] = const_arr_debug_check!([TYPE; SIZE], CONST_INIT_FN);
+/// ```
+/// 
+/// - Array is built by [`const_arr`], and then, if `debug_assertions` are enabled,
+///   init function is called again for every index and result is compared with array item
+/// - Elements are compared with `!=` in constant context, so `TYPE` is primitive type
+///   (integers, `bool`, `char`), same as in [`assert_const_arr_eq`]
+/// - Useful to check that init function gives same value for the same index,
+///   and that every index of array is initialized
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::const_arr_debug_check;
+/// 
+/// const ARR: [u32; 5] = const_arr_debug_check!([u32; 5], |i| i as u32 * 3);
+/// assert_eq!(ARR, [0, 3, 6, 9, 12]);
+/// ```
+/// 
+/// ### Panics
+/// Item that differs from init function result aborts compilation with const evaluation
+/// error. Its index is reported as out of bounds access `the len is 0 but the index is INDEX`.
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! const_arr_debug_check {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(, $extra:expr)* $(,)?) => {
+        {
+            let arr: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], $func_path $(, $extra)*);
+            if cfg!(debug_assertions) {
+                // Simple loop over every index, independent of the loop in `const_arr!`
+                let mut ind = 0;
+                while ind < arr.len() {
+                    if arr[ind] != $func_path(ind $(, $extra)*) {
+                        let failed_at_index: [usize; 0] = [];
+                        let _index = failed_at_index[ind];
+                        panic!("const_arr_debug_check!: array item differs from init function result");
+                    }
+                    ind += 1;
+                }
+            }
             arr
         }
-    ;
     };
-    ($NAME:ident, [$TYPE:ty; $SIZE:literal], |$name:ident| $body:expr ) => {
-        const $NAME: [$TYPE; $SIZE] = {
-            // Create array of proper SIZE and initialize it with garbage data 
-            // using $body with $name predefined to 0 as if every value had index 0.
-            // 
-            // There is no way to create array without initializing it and
-            // we cannot initialize it with 0-s because it isn't always valid (e.g. references)
-            // and MaybeUninit is is unsafe and unstable in const context.
-            #[allow(non_upper_case_globals)]
-            let mut arr: [$TYPE; $SIZE] = {
-                const $name: usize = 0;
-                const TEMP_ITEM: $TYPE = $body;
-                [TEMP_ITEM; $SIZE]
-            };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            let arr: [$TYPE; $SIZE] = $crate::const_arr!([$TYPE; $SIZE], |$name| $body);
+            if cfg!(debug_assertions) {
+                // Simple loop over every index, independent of the loop in `const_arr!`
+                let mut $name = 0;
+                while $name < arr.len() {
+                    if arr[$name] != $body {
+                        let failed_at_index: [usize; 0] = [];
+                        let _index = failed_at_index[$name];
+                        panic!("const_arr_debug_check!: array item differs from init function result");
+                    }
+                    $name += 1;
+                }
+            }
+            arr
+        }
+    };
+    () => {compile_error!("Please specify array type TYPE: \n      const ARR: [TYPE; SIZE] = const_arr_debug_check!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_debug_check!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      const ARR: [TYPE; SIZE] = const_arr_debug_check!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_debug_check!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i: \n      const ARR: [TYPE; SIZE] = const_arr_debug_check!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_debug_check!([i32;  10  ], |i| i as i32);\n got:  const_arr_debug_check!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      const ARR: [TYPE; SIZE] = const_arr_debug_check!([TYPE; SIZE], INIT_FN);\n e.g. const ARR: [i32;  10  ] = const_arr_debug_check!([i32;  10  ], |i| i as i32);\n got:  const_arr_debug_check!(", stringify!($($args)*), ")")); };
+}
 
-            // Initialize array with proper data from closure's body
-            let mut $name = 0;
-            while $name < $SIZE {
-                arr[$name] = $body;
-                $name += 1;
+/// ### Runtime version of [`const_arr`] macro with the same syntax
+/// #### Supports both `closure` syntax and regular function initialization.
+/// 
+/// Usage:
+/// ```ignore
+/// let arr: [TYPE; SIZE] = arr_from_fn!([TYPE; SIZE], INIT_FN);
+/// ```
+/// 
+/// - `INIT_FN` is function or closure from `array index`(`usize`) to `TYPE`.
+///   It doesn't have to be `const`, so closure can use local variables
+/// 
+/// Useful when init function cannot be `const` yet - call site stays the same
+/// except for macro name. Macro cannot be used in constant context.
+/// 
+/// ### Examples:
+/// ```
+/// use const_array_init::arr_from_fn;
+/// 
+/// let step = std::env::args().count() as i32;
+/// 
+/// let arr: [i32; 4] = arr_from_fn!([i32; 4], |i| i as i32 * step);
+/// assert_eq!(arr, [0, step, step * 2, step * 3]);
+/// ```
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! arr_from_fn {
+    ([$TYPE:ty; $SIZE:expr], $func_path:path $(,)?) => {
+        $crate::arr_from_fn!([$TYPE; $SIZE], |ind| $func_path(ind))
+    };
+    ([$TYPE:ty; $SIZE:expr], |$name:ident| $body:expr $(,)?) => {
+        {
+            // Array of indices is mapped with closure, because `core::array::from_fn`
+            // requires Rust 1.63. `map` calls closure for every index in order,
+            // so there is no need for seed value or unsafe code.
+            let mut indices = [0usize; $SIZE];
+            let mut ind = 0;
+            while ind < indices.len() {
+                indices[ind] = ind;
+                ind += 1;
             }
+            let arr: [$TYPE; $SIZE] = indices.map(|$name: usize| $body);
             arr
-        };
+        }
     };
-    ($NAME:ident, [$TYPE:ty; $SIZE:literal], |_| $body:expr ) => {
-        const $NAME: [$TYPE; $SIZE] = {
-            const TEMP_ITEM: $TYPE = $body;
-            [TEMP_ITEM; $SIZE]
-        };
+    ([$TYPE:ty; $SIZE:expr], |_| $body:expr $(,)?) => {
+        {
+            let arr: [$TYPE; $SIZE] = [(); $SIZE].map(|_| $body);
+            arr
+        }
     };
-    () => { compile_error!("Please specify array name ARR_NAME: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($_:literal) => { compile_error!("Please specify array name ARR_NAME: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident) => { compile_error!("Please specify array type TYPE: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, ) => { make_const_arr!($NAME); };
-    ($NAME:ident, [$type:ty]) => { compile_error!("Please add SIZE to array type: It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, [$type:ty;]) => { compile_error!("Please add SIZE to array type: It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, [$type:ty;$size:literal]) => { compile_error!("Please specify init function INIT_FN: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, [$type:ty;$size:literal], $num:literal) => { compile_error!("Please add |_| to last argument to turn it to closure: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, $_:tt) => { compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, $_n1:tt, $_n2:tt) => { compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };($NAME:ident, $_n1:tt, $_n2:tt, $_n3:tt) => { compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, $_n1:tt, $_n2:tt, $_fn_name:ident) => { compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-    ($NAME:ident, $_n1:tt, $_n2:tt, |$_cl:tt| $_b:tt) => { compile_error!("Array type has wrong format. It should be [TYPE; SIZE]: \n      make_const_arr!(ARR_NAME, [TYPE; SIZE], INIT_FN);\n e.g. make_const_arr!(MY_ARR  , [i32;  1024], |i| i as i32);"); };
-}
\ No newline at end of file
+    () => {compile_error!("Please specify array type TYPE: \n      let arr: [TYPE; SIZE] = arr_from_fn!([TYPE; SIZE], INIT_FN);\n e.g. let arr: [i32;  10  ] = arr_from_fn!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr] $(,)?) => {compile_error!("Please specify init function INIT_FN: \n      let arr: [TYPE; SIZE] = arr_from_fn!([TYPE; SIZE], INIT_FN);\n e.g. let arr: [i32;  10  ] = arr_from_fn!([i32;  10  ], |i| i as i32);"); };
+    ([$type:ty; $size:expr], $($args:tt)*) => {compile_error!(concat!("Init function has wrong format. It should be |i| i: \n      let arr: [TYPE; SIZE] = arr_from_fn!([TYPE; SIZE], INIT_FN);\n e.g. let arr: [i32;  10  ] = arr_from_fn!([i32;  10  ], |i| i as i32);\n got:  arr_from_fn!(", stringify!([$type; $size], $($args)*), ")")); };
+    ($($args:tt)*) => {compile_error!(concat!("Array type has wrong format. It should be [TYPE; SIZE]: \n      let arr: [TYPE; SIZE] = arr_from_fn!([TYPE; SIZE], INIT_FN);\n e.g. let arr: [i32;  10  ] = arr_from_fn!([i32;  10  ], |i| i as i32);\n got:  arr_from_fn!(", stringify!($($args)*), ")")); };
+}
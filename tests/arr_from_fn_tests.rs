@@ -0,0 +1,38 @@
+use const_array_init::arr_from_fn;
+
+fn div_2(n: usize) -> i32 {
+    n as i32 / 2
+}
+
+#[test]
+fn function_test() {
+    let arr: [i32; 3] = arr_from_fn!([i32; 3], div_2);
+    assert_eq!(arr, [0, 0, 1]);
+}
+
+#[test]
+fn capturing_closure_test() {
+    let names: Vec<String> = ["zero", "one", "two"].iter().map(|s| s.to_string()).collect();
+    let arr: [String; 3] = arr_from_fn!([String; 3], |i| format!("{}:{}", i, names[i]));
+    assert_eq!(arr, ["0:zero", "1:one", "2:two"]);
+}
+
+#[test]
+fn stateful_closure_test() {
+    let mut calls = 0;
+    let arr: [i32; 4] = arr_from_fn!([i32; 4], |_| {
+        calls += 1;
+        calls
+    });
+    assert_eq!(arr, [1, 2, 3, 4]);
+    assert_eq!(calls, 4);
+}
+
+fn generic_size<const N: usize>(offset: usize) -> [usize; N] {
+    arr_from_fn!([usize; N], |i| i + offset)
+}
+
+#[test]
+fn generic_size_test() {
+    assert_eq!(generic_size::<3>(10), [10, 11, 12]);
+}
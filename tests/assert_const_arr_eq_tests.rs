@@ -0,0 +1,26 @@
+use const_array_init::{assert_const_arr_eq, const_arr, const_arr_2d};
+
+const ARR1: [i32; 4] = const_arr!([i32; 4], |i| i as i32 * 2);
+
+assert_const_arr_eq!(ARR1, [0, 2, 4, 6]);
+
+const ARR2: [u8; 0] = const_arr!([u8; 0], |i| i as u8);
+
+assert_const_arr_eq!(ARR2, []);
+
+const ARR3: [char; 3] = const_arr!([char; 3], |i| (b'a' + i as u8) as char);
+
+assert_const_arr_eq!(ARR3, ['a', 'b', 'c']);
+
+const ARR4: [bool; 3] = const_arr!([bool; 3], |i| i % 2 == 0);
+
+assert_const_arr_eq!(ARR4, ARR4);
+
+const ARR5: [[u8; 2]; 2] = const_arr_2d!([[u8; 2]; 2], |row, col| (row * 2 + col) as u8);
+
+assert_const_arr_eq!(ARR5[1], [2, 3]);
+
+#[test]
+fn local_assert_test() {
+    assert_const_arr_eq!(ARR1, const_arr!([i32; 4], |i| i as i32 * 2));
+}
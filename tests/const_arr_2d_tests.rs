@@ -0,0 +1,56 @@
+use const_array_init::const_arr_2d;
+
+const IDENTITY: [[i32; 3]; 3] = const_arr_2d!([[i32; 3]; 3], |row, col| (row == col) as i32);
+
+#[test]
+fn identity_matrix_test() {
+    assert_eq!(IDENTITY, [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+}
+
+const fn identity(row: usize, col: usize) -> i32 {
+    if row == col { 1 } else { 0 }
+}
+
+const IDENTITY2: [[i32; 3]; 3] = const_arr_2d!([[i32; 3]; 3], identity);
+
+#[test]
+fn identity_matrix_function_test() {
+    assert_eq!(IDENTITY2, [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+}
+
+const ROWS: usize = 2;
+const COLS: usize = 4;
+
+const TABLE: [[usize; COLS]; ROWS] = const_arr_2d!([[usize; COLS]; ROWS], |r, c| r * COLS + c);
+
+#[test]
+fn const_size_test() {
+    assert_eq!(TABLE, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Cell {
+    row: u8,
+    col: u8,
+}
+
+const CELLS: [[Cell; 2]; 2] = const_arr_2d!([[Cell; 2]; 2], |row, col| Cell { row: row as u8, col: col as u8 });
+
+#[test]
+fn non_copy_closure_test() {
+    assert_eq!(
+        CELLS,
+        [
+            [Cell { row: 0, col: 0 }, Cell { row: 0, col: 1 }],
+            [Cell { row: 1, col: 0 }, Cell { row: 1, col: 1 }],
+        ]
+    );
+}
+
+#[allow(non_snake_case)]
+const ARR: [[usize; 2]; 2] = const_arr_2d!([[usize; 2]; 2], |TEMP_ROW, TEMP_ITEM| TEMP_ROW * 2 + TEMP_ITEM);
+
+#[test]
+fn internal_names_closure_test() {
+    assert_eq!(ARR, [[0, 1], [2, 3]]);
+}
@@ -0,0 +1,29 @@
+use const_array_init::const_arr_centered;
+
+const ARR1: [i32; 5] = const_arr_centered!([i32; 5], |x| x as i32);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR1, [-2, -1, 0, 1, 2]);
+}
+
+const fn square(x: isize) -> u32 {
+    (x * x) as u32
+}
+
+const ARR2: [u32; 5] = const_arr_centered!([u32; 5], square);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [4, 1, 0, 1, 4]);
+}
+
+const ARR3: [i32; 4] = const_arr_centered!([i32; 4], |x| x as i32);
+
+const ARR4: [i32; 1] = const_arr_centered!([i32; 1], |x| x as i32);
+
+#[test]
+fn even_size_test() {
+    assert_eq!(ARR3, [-2, -1, 0, 1]);
+    assert_eq!(ARR4, [0]);
+}
@@ -0,0 +1,26 @@
+use const_array_init::const_arr_checked;
+
+const ARR1: [u8; 4] = const_arr_checked!([u8; 4], |i| i * 85);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR1, [0, 85, 170, 255]);
+}
+
+const fn centered(n: usize) -> i32 {
+    n as i32 * 64 - 128
+}
+
+const ARR2: [i8; 4] = const_arr_checked!([i8; 4], centered);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [-128, -64, 0, 64]);
+}
+
+const ARR3: [u64; 3] = const_arr_checked!([u64; 3], |i| u64::MAX - i as u64);
+
+#[test]
+fn wide_type_test() {
+    assert_eq!(ARR3, [u64::MAX, u64::MAX - 1, u64::MAX - 2]);
+}
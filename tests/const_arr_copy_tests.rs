@@ -0,0 +1,40 @@
+use const_array_init::const_arr_copy;
+
+const ARR1: [i32; 4] = const_arr_copy!([i32; 4], |i| 120 / i as i32);
+
+#[test]
+fn invalid_at_0_test() {
+    assert_eq!(ARR1, [0, 120, 60, 40]);
+}
+
+const fn panics_at_0(n: usize) -> u8 {
+    if n == 0 {
+        panic!("called for index 0")
+    }
+    n as u8 * 2
+}
+
+const ARR2: [u8; 4] = const_arr_copy!([u8; 4], panics_at_0);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [0, 2, 4, 6]);
+}
+
+// 0 marks missing value, because logarithm of zero is undefined
+const fn log2_table<const N: usize>() -> [u8; N] {
+    const_arr_copy!([u8; N], |i| (usize::BITS - 1 - i.leading_zeros()) as u8)
+}
+
+#[test]
+fn generic_size_test() {
+    const ARR: [u8; 9] = log2_table();
+    assert_eq!(ARR, [0, 0, 1, 1, 2, 2, 2, 2, 3]);
+}
+
+const ARR3: [u8; 0] = const_arr_copy!([u8; 0], panics_at_0);
+
+#[test]
+fn empty_test() {
+    assert_eq!(ARR3, []);
+}
@@ -0,0 +1,33 @@
+use const_array_init::const_arr_cycle;
+
+const PAT: [u8; 3] = [0xDE, 0xAD, 0xBE];
+
+const ARR1: [u8; 2] = const_arr_cycle!([u8; 2], PAT);
+
+#[test]
+fn shorter_than_pattern_test() {
+    assert_eq!(ARR1, [0xDE, 0xAD]);
+}
+
+const ARR2: [u8; 3] = const_arr_cycle!([u8; 3], PAT);
+
+#[test]
+fn same_as_pattern_test() {
+    assert_eq!(ARR2, PAT);
+}
+
+const ARR3: [u8; 7] = const_arr_cycle!([u8; 7], PAT);
+
+#[test]
+fn longer_than_pattern_test() {
+    assert_eq!(ARR3, [0xDE, 0xAD, 0xBE, 0xDE, 0xAD, 0xBE, 0xDE]);
+}
+
+const SLICE: &[&str] = &["a", "b"];
+
+const ARR4: [&str; 5] = const_arr_cycle!([&str; 5], SLICE);
+
+#[test]
+fn slice_pattern_test() {
+    assert_eq!(ARR4, ["a", "b", "a", "b", "a"]);
+}
@@ -0,0 +1,53 @@
+use const_array_init::const_arr_debug_check;
+
+const fn square(n: usize) -> u64 {
+    (n * n) as u64
+}
+
+const ARR1: [u64; 5] = const_arr_debug_check!([u64; 5], square);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR1, [0, 1, 4, 9, 16]);
+}
+
+const ARR2: [char; 3] = const_arr_debug_check!([char; 3], |i| (b'x' + i as u8) as char);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, ['x', 'y', 'z']);
+}
+
+const fn scaled(n: usize, factor: i32) -> i32 {
+    n as i32 * factor
+}
+
+const ARR3: [i32; 3] = const_arr_debug_check!([i32; 3], scaled, -2);
+
+#[test]
+fn extra_args_test() {
+    assert_eq!(ARR3, [0, -2, -4]);
+}
+
+// Seed item and items filled by loop are checked for every size
+const ARR4: [u16; 0] = const_arr_debug_check!([u16; 0], |i| i as u16);
+const ARR5: [u16; 1] = const_arr_debug_check!([u16; 1], |i| i as u16);
+const ARR6: [u16; 2] = const_arr_debug_check!([u16; 2], |i| i as u16);
+const ARR7: [u16; 3] = const_arr_debug_check!([u16; 3], |i| i as u16);
+static ARR8: [u16; 1001] = const_arr_debug_check!([u16; 1001], |i| i as u16);
+
+#[test]
+fn sizes_test() {
+    assert_eq!(ARR4, []);
+    assert_eq!(ARR5, [0]);
+    assert_eq!(ARR6, [0, 1]);
+    assert_eq!(ARR7, [0, 1, 2]);
+    assert_eq!(ARR8[1000], 1000);
+}
+
+const ARR9: [f64; 3] = const_arr_debug_check!([f64; 3], |i| i as f64 * 1.5);
+
+#[test]
+fn float_body_test() {
+    assert_eq!(ARR9, [0.0, 1.5, 3.0]);
+}
@@ -0,0 +1,40 @@
+use const_array_init::const_arr_from_iter;
+
+const ARR1: [i32; 5] = const_arr_from_iter!([i32; 5], 0..5, |x| x);
+
+#[test]
+fn zero_start_test() {
+    assert_eq!(ARR1, [0, 1, 2, 3, 4]);
+}
+
+const ARR2: [i32; 5] = const_arr_from_iter!([i32; 5], -2..3, |x| x);
+
+#[test]
+fn negative_start_test() {
+    assert_eq!(ARR2, [-2, -1, 0, 1, 2]);
+}
+
+const START: u8 = b'a';
+
+const ARR3: [char; 3] = const_arr_from_iter!([char; 3], START..START + 3, |b| b as char);
+
+#[test]
+fn const_bounds_test() {
+    assert_eq!(ARR3, ['a', 'b', 'c']);
+}
+
+const ARR4: [u64; 0] = const_arr_from_iter!([u64; 0], 10..10, |x| x);
+
+#[test]
+fn empty_range_test() {
+    assert_eq!(ARR4, []);
+}
+
+const ARR5: [u8; 5] = const_arr_from_iter!([u8; 5], 250..255, |x| x);
+const ARR6: [u128; 3] = const_arr_from_iter!([u128; 3], u128::MAX - 3..u128::MAX, |x| u128::MAX - x);
+
+#[test]
+fn type_max_test() {
+    assert_eq!(ARR5, [250, 251, 252, 253, 254]);
+    assert_eq!(ARR6, [3, 2, 1]);
+}
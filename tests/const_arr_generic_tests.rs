@@ -0,0 +1,92 @@
+use const_array_init::{const_arr, const_arr_generic};
+
+const fn identity_table<const N: usize>() -> [usize; N] {
+    const_arr!([usize; N], |i| i)
+}
+
+#[test]
+fn generic_size_test() {
+    const TABLE: [usize; 5] = identity_table::<5>();
+    assert_eq!(TABLE, [0, 1, 2, 3, 4]);
+}
+
+const fn div_2(n: usize) -> i32 {
+    n as i32 / 2
+}
+
+const ARR1: [i32; 3] = const_arr_generic!([i32; 3], div_2);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR1, [0, 0, 1]);
+}
+
+const ARR2: [i32; 3] = const_arr_generic!([i32; 3], |i| i as i32 / 2);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, [0, 0, 1]);
+}
+
+// Trait bounds on `const fn` are allowed since Rust 1.61, so generic `TYPE` is tested in regular `fn`
+fn build<T: Copy, const N: usize>(even: T, odd: T) -> [T; N] {
+    const_arr_generic!([T; N], |i| if i % 2 == 0 { even } else { odd })
+}
+
+#[test]
+fn generic_type_test() {
+    let bytes: [u8; 4] = build(1, 2);
+    let strs: [&str; 3] = build("even", "odd");
+    assert_eq!(bytes, [1, 2, 1, 2]);
+    assert_eq!(strs, ["even", "odd", "even"]);
+}
+
+const fn scaled<const N: usize>(factor: i32) -> [i32; N] {
+    const_arr_generic!([i32; N], |i| i as i32 * factor)
+}
+
+#[test]
+fn function_argument_test() {
+    const ARR: [i32; 4] = scaled(3);
+    assert_eq!(ARR, [0, 3, 6, 9]);
+}
+
+const fn grid<const R: usize, const C: usize>(base: u8) -> [[u8; C]; R] {
+    const_arr_generic!([[u8; C]; R], |row, col| base + (row * C + col) as u8)
+}
+
+#[test]
+fn generic_2d_test() {
+    const GRID: [[u8; 4]; 2] = grid::<2, 4>(10);
+    assert_eq!(GRID, [[10, 11, 12, 13], [14, 15, 16, 17]]);
+}
+
+fn filled<T: Copy, const R: usize, const C: usize>(diagonal: T, other: T) -> [[T; C]; R] {
+    const_arr_generic!([[T; C]; R], |row, col| if row == col { diagonal } else { other })
+}
+
+#[test]
+fn generic_type_2d_test() {
+    let grid: [[char; 3]; 2] = filled('x', '.');
+    assert_eq!(grid, [['x', '.', '.'], ['.', 'x', '.']]);
+}
+
+const fn unused_index<const N: usize>(value: u8) -> [u8; N] {
+    const_arr_generic!([u8; N], |i| value)
+}
+
+#[test]
+fn unused_index_test() {
+    const ARR: [u8; 3] = unused_index(7);
+    assert_eq!(ARR, [7, 7, 7]);
+}
+
+const fn columns<const R: usize, const C: usize>() -> [[usize; C]; R] {
+    const_arr_generic!([[usize; C]; R], |row, col| col)
+}
+
+#[test]
+fn unused_row_2d_test() {
+    const GRID: [[usize; 2]; 2] = columns();
+    assert_eq!(GRID, [[0, 1], [0, 1]]);
+}
@@ -0,0 +1,33 @@
+use const_array_init::const_arr_grid;
+
+const ROWS: usize = 2;
+const COLS: usize = 3;
+
+const GRID1: [i32; ROWS * COLS] = const_arr_grid!([i32; ROWS * COLS], ROWS, COLS, |row, col| (row * 10 + col) as i32);
+
+#[test]
+fn grid_cells_test() {
+    assert_eq!(GRID1[0], 0);
+    assert_eq!(GRID1[2], 2);
+    assert_eq!(GRID1[COLS], 10);
+    assert_eq!(GRID1[COLS + 2], 12);
+    assert_eq!(GRID1, [0, 1, 2, 10, 11, 12]);
+}
+
+const fn is_diagonal(row: usize, col: usize) -> bool {
+    row == col
+}
+
+const GRID2: [bool; 9] = const_arr_grid!([bool; 9], 3, 3, is_diagonal);
+
+#[test]
+fn const_fn_test() {
+    assert_eq!(GRID2, [true, false, false, false, true, false, false, false, true]);
+}
+
+const GRID3: [u8; 0] = const_arr_grid!([u8; 0], 0, 4, |row, col| (row + col) as u8);
+
+#[test]
+fn empty_test() {
+    assert_eq!(GRID3, []);
+}
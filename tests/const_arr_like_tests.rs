@@ -0,0 +1,50 @@
+use const_array_init::const_arr_like;
+
+const OTHER: [u8; 4] = [1, 2, 3, 4];
+
+const ARR1: [u16; 4] = const_arr_like!(OTHER, |i| -> u16 { OTHER[i] as u16 * 1000 });
+const ARR5: [u16; 4] = const_arr_like!([u16; _], OTHER, |i| OTHER[i] as u16 * 1000);
+
+#[test]
+fn other_type_test() {
+    assert_eq!(ARR1, [1000, 2000, 3000, 4000]);
+    assert_eq!(ARR5, [1000, 2000, 3000, 4000]);
+}
+
+const fn square(n: usize) -> u64 {
+    (n * n) as u64
+}
+
+const ARR2: [u64; OTHER.len()] = const_arr_like!([u64; _], OTHER, square);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [0, 1, 4, 9]);
+}
+
+#[derive(Debug, PartialEq)]
+struct User {
+    id: u32,
+}
+
+const ARR3: [User; 4] = const_arr_like!(OTHER, |i| -> User { User { id: i as u32 } });
+
+#[test]
+fn non_copy_test() {
+    assert_eq!(ARR3[3], User { id: 3 });
+}
+
+const EMPTY: [u8; 0] = [];
+const ARR4: [i32; 0] = const_arr_like!(EMPTY, |i| -> i32 { i as i32 });
+
+#[test]
+fn empty_test() {
+    assert_eq!(ARR4, []);
+}
+
+const ARR6: [i8; 4] = const_arr_like!([i8; _], OTHER; -1);
+
+#[test]
+fn value_test() {
+    assert_eq!(ARR6, [-1; 4]);
+}
@@ -0,0 +1,33 @@
+use const_array_init::{const_arr, const_arr_none};
+
+// Stand-in for `String`: const-constructible and not `Copy`
+#[derive(Debug, PartialEq, Eq)]
+struct Name(&'static str);
+
+const ARR1: [Option<Name>; 3] = const_arr_none!([Option<Name>; 3]);
+
+#[test]
+fn none_test() {
+    assert_eq!(ARR1, [None, None, None]);
+}
+
+const ARR2: [Option<Name>; 4] = const_arr!([Option<Name>; 4], |i| if i % 2 == 0 { None } else { Some(Name("odd")) });
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, [None, Some(Name("odd")), None, Some(Name("odd"))]);
+}
+
+const fn name_at(n: usize) -> Option<Name> {
+    match n {
+        1 => Some(Name("one")),
+        _ => None,
+    }
+}
+
+const ARR3: [Option<Name>; 3] = const_arr!([Option<Name>; 3], name_at);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR3, [None, Some(Name("one")), None]);
+}
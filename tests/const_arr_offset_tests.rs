@@ -0,0 +1,30 @@
+use const_array_init::const_arr_offset;
+
+const ARR1: [i32; 3] = const_arr_offset!([i32; 3], 100, |i| i as i32);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR1, [100, 101, 102]);
+}
+
+const fn square(n: usize) -> u64 {
+    (n * n) as u64
+}
+
+const START: usize = 10;
+
+const ARR2: [u64; 3] = const_arr_offset!([u64; 3], START, square);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [100, 121, 144]);
+}
+
+const ARR3: [usize; 2] = const_arr_offset!([usize; 2], usize::MAX - 1, |i| i);
+const ARR4: [usize; 0] = const_arr_offset!([usize; 0], usize::MAX, |i| i);
+
+#[test]
+fn max_offset_test() {
+    assert_eq!(ARR3, [usize::MAX - 1, usize::MAX]);
+    assert_eq!(ARR4, []);
+}
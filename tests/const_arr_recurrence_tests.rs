@@ -0,0 +1,40 @@
+use const_array_init::const_arr_recurrence;
+
+const FIB: [u64; 15] = const_arr_recurrence!([u64; 15], [0, 1], |i, arr| arr[i - 1] + arr[i - 2]);
+
+#[test]
+fn fibonacci_test() {
+    assert_eq!(FIB, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377]);
+}
+
+const fn tribonacci(i: usize, arr: &[u32]) -> u32 {
+    arr[i - 1] + arr[i - 2] + arr[i - 3]
+}
+
+const TRIB: [u32; 8] = const_arr_recurrence!([u32; 8], [0, 0, 1], tribonacci);
+
+#[test]
+fn function_test() {
+    assert_eq!(TRIB, [0, 0, 1, 1, 2, 4, 7, 13]);
+}
+
+const POW: [u32; 5] = const_arr_recurrence!([u32; 5], [1], |i, arr| arr[i - 1] * 3);
+
+#[test]
+fn single_base_test() {
+    assert_eq!(POW, [1, 3, 9, 27, 81]);
+}
+
+const ONLY_BASE: [u8; 2] = const_arr_recurrence!([u8; 2], [7, 8], |i, arr| arr[i - 1]);
+
+#[test]
+fn only_base_test() {
+    assert_eq!(ONLY_BASE, [7, 8]);
+}
+
+const HALVES: [f64; 4] = const_arr_recurrence!([f64; 4], [8.0], |i, arr| arr[i - 1] * 0.5);
+
+#[test]
+fn float_body_test() {
+    assert_eq!(HALVES, [8.0, 4.0, 2.0, 1.0]);
+}
@@ -0,0 +1,47 @@
+use const_array_init::const_arr_ref;
+
+const REF1: &[u8; 4] = const_arr_ref!([u8; 4], |i| i as u8);
+
+#[test]
+fn ref_test() {
+    assert_eq!(REF1, &[0, 1, 2, 3]);
+}
+
+fn sum(bytes: &[u8]) -> u32 {
+    bytes.iter().map(|&b| b as u32).sum()
+}
+
+#[test]
+fn expected_slice_test() {
+    assert_eq!(sum(const_arr_ref!([u8; 4], |i| i as u8)), 6);
+}
+
+const SLICE: &[u8] = const_arr_ref!([u8; 3], |i| i as u8 * 10);
+
+#[test]
+fn coerce_to_slice_test() {
+    assert_eq!(SLICE, &[0, 10, 20]);
+    assert_eq!(SLICE.len(), 3);
+}
+
+fn static_ref() -> &'static [u16; 2] {
+    const_arr_ref!([u16; 2]; 7)
+}
+
+#[test]
+fn static_lifetime_test() {
+    assert_eq!(static_ref(), &[7, 7]);
+}
+
+const fn to_i32(n: usize) -> i32 {
+    n as i32 - 1
+}
+
+const EMPTY: &[i32] = const_arr_ref!([i32; 0], to_i32);
+const REF2: &[i32; 3] = const_arr_ref!([i32; 3], to_i32);
+
+#[test]
+fn const_fn_test() {
+    assert!(EMPTY.is_empty());
+    assert_eq!(REF2, &[-1, 0, 1]);
+}
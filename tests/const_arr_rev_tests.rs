@@ -0,0 +1,55 @@
+use const_array_init::const_arr_rev;
+
+const ARR1: [i32; 3] = const_arr_rev!([i32; 3], div_2);
+
+const fn div_2(n: usize) -> i32 {
+    n as i32 / 2
+}
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR1, [0, 0, 1]);
+}
+
+const ARR2: [i32; 3] = const_arr_rev!([i32; 3], |i| i as i32 / 2);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, [0, 0, 1]);
+}
+
+const ARR3: [u32; 5] = const_arr_rev!([u32; 5], |i, arr| {
+    if i == 4 {
+        10
+    } else {
+        arr[i + 1] + 1
+    }
+});
+
+#[test]
+fn successor_closure_test() {
+    assert_eq!(ARR3, [14, 13, 12, 11, 10]);
+}
+
+// Slots before current index still hold the seed value
+const ARR4: [usize; 4] = const_arr_rev!([usize; 4], |i, arr| if i == 3 { 7 } else { arr[0] + i });
+
+#[test]
+fn seeded_slots_closure_test() {
+    assert_eq!(ARR4, [7, 8, 9, 7]);
+}
+
+const ARR5: [u8; 1] = const_arr_rev!([u8; 1], |i, arr| if arr.is_empty() { 42 } else { i as u8 });
+
+#[test]
+fn single_item_closure_test() {
+    assert_eq!(ARR5, [42]);
+}
+
+#[allow(non_snake_case)]
+const ARR6: [usize; 3] = const_arr_rev!([usize; 3], |TEMP_ITEM, arr| if arr.is_empty() { 0 } else { arr[TEMP_ITEM + 1] + 1 });
+
+#[test]
+fn internal_names_closure_test() {
+    assert_eq!(ARR6, [2, 1, 0]);
+}
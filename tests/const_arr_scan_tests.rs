@@ -0,0 +1,40 @@
+use const_array_init::const_arr_scan;
+
+const FIB: [u64; 10] = const_arr_scan!([u64; 10], (0, 1), |i, acc| (acc.0, (acc.1, acc.0 + acc.1)));
+
+#[test]
+fn fibonacci_test() {
+    assert_eq!(FIB, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+}
+
+const OFFSETS: [usize; 4] = const_arr_scan!([usize; 4], 0, |i, offset| (offset, offset + i + 1));
+
+#[test]
+fn index_test() {
+    assert_eq!(OFFSETS, [0, 1, 3, 6]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+const LENGTHS: [u32; 3] = [2, 5, 1];
+
+const RANGES: [Range; 3] = const_arr_scan!([Range; 3], 0, |i, start| {
+    let end = start + LENGTHS[i];
+    (Range { start, end }, end)
+});
+
+#[test]
+fn struct_item_test() {
+    assert_eq!(RANGES, [Range { start: 0, end: 2 }, Range { start: 2, end: 7 }, Range { start: 7, end: 8 }]);
+}
+
+const SINGLE: [u32; 1] = const_arr_scan!([u32; 1], 7, |i, acc| (acc, acc + 1));
+
+#[test]
+fn single_item_test() {
+    assert_eq!(SINGLE, [7]);
+}
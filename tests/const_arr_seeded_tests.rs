@@ -0,0 +1,31 @@
+use const_array_init::const_arr_seeded;
+
+const fn inv(n: usize) -> usize {
+    100 / n
+}
+
+const ARR1: [usize; 4] = const_arr_seeded!([usize; 4], 0, inv);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR1, [0, 100, 50, 33]);
+}
+
+const ARR2: [usize; 4] = const_arr_seeded!([usize; 4], usize::MAX, |i| 100 / i);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, [usize::MAX, 100, 50, 33]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct User {
+    id: u32,
+}
+
+const ARR3: [User; 3] = const_arr_seeded!([User; 3], User { id: 42 }, |i| User { id: i as u32 });
+
+#[test]
+fn non_copy_closure_test() {
+    assert_eq!(ARR3, [User { id: 42 }, User { id: 1 }, User { id: 2 }]);
+}
@@ -0,0 +1,29 @@
+use const_array_init::const_arr_strict;
+
+const ARR1: [i32; 4] = const_arr_strict!([i32; 4], |i| i as i32);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR1, [0, 1, 2, 3]);
+}
+
+const fn div_2(n: usize) -> i32 {
+    n as i32 / 2
+}
+
+const ARR2: [i32; 4] = const_arr_strict!([i32; 4], div_2);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [0, 0, 1, 1]);
+}
+
+const ARR3: [i32; 1] = const_arr_strict!([i32; 1], |_| 5);
+
+const ARR4: [i32; 0] = const_arr_strict!([i32; 0], |_| 5);
+
+#[test]
+fn short_array_test() {
+    assert_eq!(ARR3, [5]);
+    assert_eq!(ARR4, []);
+}
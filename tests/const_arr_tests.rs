@@ -1,4 +1,8 @@
+#![forbid(non_upper_case_globals)]
+
 use const_array_init::const_arr;
+use core::num::NonZeroU32;
+use core::time::Duration;
 
 const ARR1: [i32; 3] = const_arr!([i32; 3], div_2);
 
@@ -47,6 +51,7 @@ fn super_advanced_closure_test() {
     assert_eq!(ARR5, [User { id: 0 }, User { id: 1 }, User { id: 2 },]);
 }
 
+#[allow(clippy::unnecessary_cast)]
 const ARR6: [User; 3] = const_arr!([User; 3], |_| User { id: 1 as u32 });
 
 #[test]
@@ -65,3 +70,900 @@ const ARR7: [User; 3] = const_arr!([User; 3], create_user);
 fn super_advanced_func_test() {
     assert_eq!(ARR7, [User { id: 0 }, User { id: 1 }, User { id: 2 },]);
 }
+
+const N: usize = 8;
+
+const ARR8: [usize; N] = const_arr!([usize; N], |i| i * 2);
+
+#[test]
+fn const_size_test() {
+    assert_eq!(ARR8, [0, 2, 4, 6, 8, 10, 12, 14]);
+}
+
+const ARR9: [i32; N / 2] = const_arr!([i32; N / 2], div_2);
+
+#[test]
+fn const_expr_size_test() {
+    assert_eq!(ARR9, [0, 0, 1, 1]);
+}
+
+const fn squares<const SIZE: usize>() -> [usize; SIZE] {
+    const_arr!([usize; SIZE], |i| i * i)
+}
+
+#[test]
+fn generic_size_test() {
+    const SQUARES: [usize; 4] = squares::<4>();
+    assert_eq!(SQUARES, [0, 1, 4, 9]);
+}
+
+// Sum of 0..=n computed with a loop, so index 0 should only be paid for once
+const fn triangular(n: usize) -> u64 {
+    let mut sum = 0;
+    let mut k = 0;
+    while k <= n {
+        sum += k as u64;
+        k += 1;
+    }
+    sum
+}
+
+const ARR10: [u64; 4] = const_arr!([u64; 4], triangular);
+
+#[test]
+fn seed_index_func_test() {
+    assert_eq!(ARR10[0], 0);
+    assert_eq!(ARR10, [0, 1, 3, 6]);
+}
+
+const ARR11: [i32; 5] = const_arr!([i32; 5], |i, arr| {
+    if i == 0 {
+        1
+    } else {
+        arr[i - 1] + i as i32 + 1
+    }
+});
+
+#[test]
+fn cumulative_closure_test() {
+    assert_eq!(ARR11, [1, 3, 6, 10, 15]);
+}
+
+// Slots after current index still hold the seed value
+const ARR12: [usize; 4] = const_arr!([usize; 4], |i, arr| if i == 0 { 7 } else { arr[arr.len() - 1] + i });
+
+#[test]
+fn seeded_slots_closure_test() {
+    assert_eq!(ARR12, [7, 8, 9, 10]);
+}
+
+const ARR13: [u8; 256] = const_arr!([u8; 256], |b: u8| b.wrapping_mul(3));
+
+#[test]
+fn typed_index_closure_test() {
+    assert_eq!(ARR13[0], 0);
+    assert_eq!(ARR13[85], 255);
+    assert_eq!(ARR13[86], 2);
+    assert_eq!(ARR13[255], 253);
+}
+
+const ARR14: [i32; 3] = const_arr!([i32; 3], |arr| arr as i32 / 2);
+
+const ARR15: [i32; 3] = const_arr!([i32; 3], |ind| ind as i32 / 2);
+
+#[allow(non_snake_case)]
+const ARR16: [i32; 3] = const_arr!([i32; 3], |TEMP_ITEM| TEMP_ITEM as i32 / 2);
+
+const ARR17: [i32; 3] = const_arr!([i32; 3], |ind, arr| if ind == 0 { 0 } else { arr[ind - 1] + 1 });
+
+const ARR18: [i32; 3] = const_arr!([i32; 3], |ind: u8| ind as i32 / 2);
+
+#[test]
+fn internal_names_closure_test() {
+    assert_eq!(ARR14, [0, 0, 1]);
+    assert_eq!(ARR15, [0, 0, 1]);
+    assert_eq!(ARR16, [0, 0, 1]);
+    assert_eq!(ARR17, [0, 1, 2]);
+    assert_eq!(ARR18, [0, 0, 1]);
+}
+
+const ARR19: [i32; 3] = const_arr!([i32; 3], |i| i as i32 / 2,);
+
+const ARR20: [i32; 3] = const_arr!([i32; 3], div_2,);
+
+const ARR21: [i32; 3] = const_arr!([i32; 3], |_| 4 / 2,);
+
+#[test]
+fn trailing_comma_test() {
+    assert_eq!(ARR19, [0, 0, 1]);
+    assert_eq!(ARR20, [0, 0, 1]);
+    assert_eq!(ARR21, [2, 2, 2]);
+}
+
+const ARR22: [u8; 16] = const_arr!([u8; 16]; 0xFF);
+
+#[test]
+fn fill_test() {
+    assert_eq!(ARR22, [0xFF; 16]);
+}
+
+const ADMIN: User = User { id: 0 };
+
+const ARR23: [User; 3] = const_arr!([User; 3]; ADMIN);
+
+#[test]
+fn non_copy_fill_test() {
+    assert_eq!(ARR23, [User { id: 0 }, User { id: 0 }, User { id: 0 }]);
+}
+
+const fn mul<const K: u32>(n: usize) -> u32 {
+    n as u32 * K
+}
+
+const ARR24: [u32; 4] = const_arr!([u32; 4], mul::<3>);
+
+#[test]
+fn generic_function_test() {
+    assert_eq!(ARR24, [0, 3, 6, 9]);
+}
+
+impl User {
+    const fn new(n: usize) -> User {
+        User { id: n as u32 }
+    }
+}
+
+const ARR25: [User; 3] = const_arr!([User; 3], User::new);
+
+mod users {
+    pub(super) const fn create_user(n: usize) -> super::User {
+        super::User { id: n as u32 + 1 }
+    }
+}
+
+const ARR26: [User; 3] = const_arr!([User; 3], users::create_user);
+
+#[test]
+fn path_function_test() {
+    assert_eq!(ARR25, [User { id: 0 }, User { id: 1 }, User { id: 2 }]);
+    assert_eq!(ARR26, [User { id: 1 }, User { id: 2 }, User { id: 3 }]);
+}
+
+const ARR27: [i32; 3] = const_arr!([i32; 3], |i| -> i32 { i as i32 / 2 });
+
+const ARR28: [i32; 3] = const_arr!([_; 3], |i| -> i32 { i as i32 / 2 });
+
+const ARR29: [User; 3] = const_arr!([_; 3], |i| -> User {
+    let id = i as u32;
+    User { id }
+});
+
+#[test]
+fn return_type_closure_test() {
+    assert_eq!(ARR27, [0, 0, 1]);
+    assert_eq!(ARR28, [0, 0, 1]);
+    assert_eq!(ARR29, [User { id: 0 }, User { id: 1 }, User { id: 2 }]);
+}
+
+const THRESHOLD: i32 = 10;
+
+const ARR30: [i32; 3] = const_arr!([i32; 3], |i| i as i32 + THRESHOLD);
+
+const ARR31: [i32; 3] = {
+    const STEP: i32 = 2;
+    const_arr!([i32; 3], |i| i as i32 * STEP + THRESHOLD)
+};
+
+impl User {
+    const ADMIN_ID: u32 = 100;
+}
+
+const ARR32: [User; 2] = const_arr!([User; 2], |i| User { id: User::ADMIN_ID + i as u32 });
+
+#[test]
+fn outer_const_test() {
+    assert_eq!(ARR30, [10, 11, 12]);
+    assert_eq!(ARR31, [10, 12, 14]);
+    assert_eq!(ARR32, [User { id: 100 }, User { id: 101 }]);
+}
+
+const fn invalid_at_0(n: usize) -> i32 {
+    100 / n as i32
+}
+
+const ARR33: [i32; 0] = const_arr!([i32; 0], invalid_at_0);
+
+const ARR34: [i32; 0] = const_arr!([i32; 0], |i| 100 / i as i32);
+
+const ARR35: [i32; 0] = const_arr!([i32; 0], |i, arr| arr[i] + 1);
+
+const ARR36: [i32; 0] = const_arr!([i32; 0], |_| invalid_at_0(0));
+
+const ARR37: [u64; 0] = const_arr!([_; 0], |i| -> u64 { 1 << (64 - i) });
+
+#[test]
+fn zero_length_test() {
+    assert_eq!(ARR33, []);
+    assert_eq!(ARR34, []);
+    assert_eq!(ARR35, []);
+    assert_eq!(ARR36, []);
+    assert_eq!(ARR37, []);
+}
+
+mod conversions {
+    pub const fn to_i32(n: usize) -> i32 {
+        n as i32
+    }
+}
+
+use conversions::to_i32 as convert;
+
+const ARR38: [i32; 3] = const_arr!([i32; 3], convert);
+
+#[test]
+fn renamed_function_test() {
+    assert_eq!(ARR38, [0, 1, 2]);
+}
+
+const ARR39: [i32; 3] = const_arr!([i32; 3], |i| i as i32 + { let n = 2; n * n });
+
+const ARR40: [i32; 3] = const_arr!([i32; 3], |i| { let n = 2; n + 1 } * i as i32);
+
+const ARR41: [i32; 3] = const_arr!([i32; 3], |i| if i % 2 == 0 { 1 } else { -1 } * 10);
+
+const ARR42: [i32; 3] = const_arr!([i32; 3], |i, arr| { if i == 0 { 1 } else { arr[i - 1] } } + { 1 });
+
+const ARR43: [i32; 3] = const_arr!([i32; 3], |i: u8| match i { 0 => 5, _ => 7 } - { 1 });
+
+#[test]
+fn block_in_expression_test() {
+    assert_eq!(ARR39, [4, 5, 6]);
+    assert_eq!(ARR40, [0, 3, 6]);
+    assert_eq!(ARR41, [10, -10, 10]);
+    assert_eq!(ARR42, [2, 3, 4]);
+    assert_eq!(ARR43, [4, 6, 6]);
+}
+
+type Id = u32;
+
+type Users = [User; 2];
+
+const LEN: usize = 4;
+
+const ARR44: [Id; LEN] = const_arr!([Id; LEN], |i| i as Id * 2);
+
+const ARR45: [Option<Id>; LEN] = const_arr!([Option<Id>; LEN]; None);
+
+const ARR46: [Users; 2] = const_arr!([Users; 2], |i| [User { id: i as Id }, User { id: i as Id + 1 }]);
+
+#[test]
+fn type_alias_test() {
+    assert_eq!(ARR44, [0, 2, 4, 6]);
+    assert_eq!(ARR45, [None; LEN]);
+    assert_eq!(ARR46, [[User { id: 0 }, User { id: 1 }], [User { id: 1 }, User { id: 2 }]]);
+}
+
+// Rust 1.57 stops constant evaluation at about 150_000 items
+static ARR47: [u8; 100_000] = const_arr!([u8; 100_000], |i| (i % 251) as u8);
+
+#[test]
+fn large_size_test() {
+    assert_eq!(ARR47[0], 0);
+    assert_eq!(ARR47[99_999], (99_999 % 251) as u8);
+}
+
+const fn scaled(n: usize, factor: i32) -> i32 {
+    n as i32 * factor
+}
+
+const fn affine(n: usize, factor: i32, offset: i32) -> i32 {
+    n as i32 * factor + offset
+}
+
+const FACTOR: i32 = 3;
+
+const ARR48: [i32; 4] = const_arr!([i32; 4], scaled, 10);
+
+const ARR49: [i32; 4] = const_arr!([i32; 4], affine, FACTOR, -1,);
+
+const ARR50: [i32; 0] = const_arr!([i32; 0], affine, 1, 2);
+
+// Seed is computed by `affine(0, 100, 5)`, so index 0 isn't left as garbage
+const ARR51: [i32; 1] = const_arr!([i32; 1], affine, 100, 5);
+
+#[test]
+fn extra_arguments_test() {
+    assert_eq!(ARR48, [0, 10, 20, 30]);
+    assert_eq!(ARR49, [-1, 2, 5, 8]);
+    assert_eq!(ARR50, []);
+    assert_eq!(ARR51, [5]);
+}
+
+const ARR52: [u8; 0x100] = const_arr!([u8; 0x100], |i| i as u8);
+
+const ARR53: [u8; 1_024] = const_arr!([u8; 1_024], |i| i as u8);
+
+const ARR54: [u8; 256usize] = const_arr!([u8; 256usize], |i| i as u8);
+
+const ARR55: [u8; 0b100] = const_arr!([u8; 0b100], |i| i as u8);
+
+#[test]
+fn size_literal_test() {
+    assert_eq!(ARR52.len(), 256);
+    assert_eq!(ARR52[0xFF], 0xFF);
+    assert_eq!(ARR53.len(), 1024);
+    assert_eq!(ARR53[1023], 0xFF);
+    assert_eq!(ARR54.len(), 256);
+    assert_eq!(ARR55, [0, 1, 2, 3]);
+}
+
+const fn default_user() -> User {
+    User { id: 42 }
+}
+
+const ARR56: [User; 3] = const_arr!([User; 3]; default_user());
+
+const ARR57: [User; 2] = const_arr!([User; 2], |_| default_user());
+
+#[test]
+fn zero_argument_function_test() {
+    assert_eq!(ARR56, [User { id: 42 }, User { id: 42 }, User { id: 42 }]);
+    assert_eq!(ARR57, [User { id: 42 }, User { id: 42 }]);
+}
+
+const ARR58: [[u8; 4]; 3] = const_arr!([[u8; 4]; 3], |i| [i as u8; 4]);
+
+const ARR59: [[User; 2]; 3] = const_arr!([[User; 2]; 3], |i| [User { id: i as u32 }, User { id: i as u32 * 10 }]);
+
+const ARR60: [[User; 2]; 2] = const_arr!([[User; 2]; 2]; [User { id: 1 }, User { id: 2 }]);
+
+#[test]
+fn array_of_arrays_test() {
+    assert_eq!(ARR58, [[0; 4], [1; 4], [2; 4]]);
+    assert_eq!(ARR59[2], [User { id: 2 }, User { id: 20 }]);
+    assert_eq!(ARR60[1], [User { id: 1 }, User { id: 2 }]);
+}
+
+#[test]
+fn inferred_let_test() {
+    let arr = const_arr!([i32; 3], |i| i as i32 * 3);
+    assert_eq!(arr, [0, 3, 6]);
+}
+
+const ARR61: [i32; 3] = const_arr!([i32; 3], |i| {
+    // Comment before statement
+    #[cfg(all())]
+    let base = 10; // Trailing comment
+    #[cfg(any())]
+    let base = 20;
+    /* Block comment */
+    let scaled = i as i32;
+    base + scaled
+});
+
+const ARR62: [i32; 3] = const_arr!([i32; 3], |i, arr| {
+    // Previous item is available from index 1
+    #[cfg(all())]
+    let prev = if i == 0 { 0 } else { arr[i - 1] };
+    prev + 2
+});
+
+#[test]
+fn comments_and_cfg_test() {
+    assert_eq!(ARR61, [10, 11, 12]);
+    assert_eq!(ARR62, [2, 4, 6]);
+}
+
+struct Tables {
+    squares: [u8; 4],
+    halves: [u8; 4],
+    sums: [u8; 4],
+    zeros: [u8; 2],
+    empty: [u8; 0],
+}
+
+const fn half(n: usize) -> u8 {
+    n as u8 / 2
+}
+
+const TABLES: Tables = Tables {
+    squares: const_arr!([u8; 4], |i| (i * i) as u8),
+    halves: const_arr!([u8; 4], half),
+    sums: const_arr!([u8; 4], |i, arr| if i == 0 { 1 } else { arr[i - 1] + 1 }),
+    zeros: const_arr!([u8; 2]; 0),
+    empty: const_arr!([u8; 0], |i| i as u8),
+};
+
+#[test]
+fn struct_field_test() {
+    assert_eq!(TABLES.squares, [0, 1, 4, 9]);
+    assert_eq!(TABLES.halves, [0, 0, 1, 1]);
+    assert_eq!(TABLES.sums, [1, 2, 3, 4]);
+    assert_eq!(TABLES.zeros, [0, 0]);
+    assert_eq!(TABLES.empty, []);
+}
+
+struct Wrapper([i16; 3]);
+
+const WRAPPER: Wrapper = Wrapper(const_arr!([i16; 3], |i| -(i as i16)));
+
+#[test]
+fn tuple_struct_field_test() {
+    assert_eq!(WRAPPER.0, [0, -1, -2]);
+}
+
+struct Gen;
+
+impl Gen {
+    const fn gen(n: usize) -> u32 {
+        n as u32 + 100
+    }
+}
+
+struct Scale<const K: u32>;
+
+impl<const K: u32> Scale<K> {
+    const fn gen(n: usize) -> u32 {
+        n as u32 * K
+    }
+}
+
+mod tables {
+    pub struct Gen;
+
+    impl Gen {
+        pub const fn gen(n: usize) -> u32 {
+            n as u32 * n as u32
+        }
+    }
+}
+
+const ARR65: [u32; 8] = const_arr!([u32; 8], Gen::gen);
+const ARR66: [u32; 4] = const_arr!([u32; 4], Scale::<5>::gen);
+const ARR67: [u32; 4] = const_arr!([u32; 4], tables::Gen::gen);
+
+#[test]
+fn associated_fn_test() {
+    assert_eq!(ARR65, [100, 101, 102, 103, 104, 105, 106, 107]);
+    assert_eq!(ARR66, [0, 5, 10, 15]);
+    assert_eq!(ARR67, [0, 1, 4, 9]);
+}
+
+const fn mix_u128(n: usize) -> u128 {
+    let mut x = (n as u128 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835);
+    x ^= x >> 67;
+    x.rotate_left(n as u32)
+}
+
+static ARR68: [u128; 64] = const_arr!([u128; 64], |i| mix_u128(i));
+
+#[test]
+fn u128_table_test() {
+    for (i, item) in ARR68.iter().enumerate() {
+        assert_eq!(*item, mix_u128(i));
+    }
+    assert_eq!(ARR68[0], 0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835 ^ (0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835 >> 67));
+}
+
+struct Idx(usize);
+
+const ARR69: [u32; 4] = const_arr!([u32; 4], |Idx(i)| i as u32 + 1);
+const ARR70: [u32; 3] = const_arr!([u32; 3], |Idx(_)| 7);
+const ARR71: [u32; 3] = const_arr!([u32; 3], |Idx(mut i)| {
+    i *= 10;
+    i as u32
+});
+const ARR72: [u32; 0] = const_arr!([u32; 0], |Idx(i)| 100 / i as u32);
+
+#[test]
+fn wrapped_index_test() {
+    assert_eq!(ARR69, [1, 2, 3, 4]);
+    assert_eq!(ARR70, [7, 7, 7]);
+    assert_eq!(ARR71, [0, 10, 20]);
+    assert_eq!(ARR72, []);
+}
+
+const LOOKUP: [&str; 3] = ["zero", "one", "two"];
+
+const ARR73: [&str; 3] = const_arr!([&'static str; 3], |i| LOOKUP[2 - i]);
+
+const BYTES: &[u8] = b"abcdef";
+
+// `split_at` is const only since Rust 1.71, so window is cut with slice patterns
+const fn window(n: usize) -> &'static [u8] {
+    let mut window = BYTES;
+    while window.len() > BYTES.len() - n * 2 {
+        if let [_, rest @ ..] = window {
+            window = rest;
+        }
+    }
+    while window.len() > 2 {
+        if let [rest @ .., _] = window {
+            window = rest;
+        }
+    }
+    window
+}
+
+const ARR74: [&[u8]; 3] = const_arr!([&'static [u8]; 3], window);
+
+const ARR75: [&str; 3] = const_arr!([&str; 3], |i| LOOKUP[i]);
+const ARR76: [&str; 3] = const_arr!([&str; 3], |i: u8| LOOKUP[i as usize]);
+const ARR77: [&str; 3] = const_arr!([&'static str; 3], |i, arr| {
+    if i == 0 { LOOKUP[0] } else { arr[i - 1] }
+});
+const ARR78: [&str; 2] = const_arr!([&str; 2]; "none");
+
+#[test]
+fn reference_items_test() {
+    assert_eq!(ARR73, ["two", "one", "zero"]);
+    assert_eq!(ARR74, [b"ab", b"cd", b"ef"]);
+    assert_eq!(ARR75, ["zero", "one", "two"]);
+    assert_eq!(ARR76, ["zero", "one", "two"]);
+    assert_eq!(ARR77, ["zero", "zero", "zero"]);
+    assert_eq!(ARR78, ["none", "none"]);
+}
+
+const fn add_one(x: u32) -> u32 {
+    x + 1
+}
+
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+const fn square(x: u32) -> u32 {
+    x * x
+}
+
+const ARR79: [fn(u32) -> u32; 4] = const_arr!([fn(u32) -> u32; 4], |i| match i % 3 {
+    0 => add_one,
+    1 => double,
+    _ => square,
+});
+
+// `const fn` can return function pointer only since Rust 1.61, so items are selected in closure
+const ARR80: [fn(u32) -> u32; 2] = const_arr!([fn(u32) -> u32; 2], |i| if i == 0 { square } else { add_one });
+
+#[test]
+fn fn_pointer_items_test() {
+    let results: [u32; 4] = ARR79.map(|f| f(5));
+    assert_eq!(results, [6, 10, 25, 6]);
+    assert_eq!(ARR80[0](3), 9);
+    assert_eq!(ARR80[1](3), 4);
+}
+
+const ARR81: [char; 5] = const_arr!([char; 5], |i| (b'a' + i as u8) as char);
+// `char::from_u32` is const only since Rust 1.67, so chars wider than `u8` come from lookup table
+const GREEK: [char; 3] = ['α', 'β', 'γ'];
+const ARR82: [char; 5] = const_arr!([char; 5], |i| GREEK[i % GREEK.len()]);
+const ARR83: [char; 3] = const_arr!([char; 3], |i| (b'0' + i as u8) as char);
+
+#[test]
+fn char_items_test() {
+    assert_eq!(ARR81, ['a', 'b', 'c', 'd', 'e']);
+    assert_eq!(ARR82, ['α', 'β', 'γ', 'α', 'β']);
+    assert_eq!(ARR83, ['0', '1', '2']);
+}
+
+const fn offset_of_item(n: usize) -> usize {
+    n * core::mem::size_of::<u64>()
+}
+
+const ARR84: [usize; 4] = const_arr!([usize; 4], |i| i * core::mem::size_of::<u32>());
+const ARR85: [usize; 4] = const_arr!([usize; 4], offset_of_item);
+const ARR86: [usize; 3] = const_arr!([usize; 3], |i, arr| {
+    if i == 0 { core::mem::align_of::<u16>() } else { arr[i - 1] + core::mem::size_of::<[u8; 3]>() }
+});
+
+#[test]
+fn size_of_test() {
+    assert_eq!(ARR84, [0, 4, 8, 12]);
+    assert_eq!(ARR85, [0, 8, 16, 24]);
+    assert_eq!(ARR86, [2, 5, 8]);
+}
+
+const ARR87: [i32; 3] = const_arr!([i32; 3], || 7);
+const ARR88: [i32; 0] = const_arr!([i32; 0], || 7);
+const ARR89: [&str; 2] = const_arr!([&str; 2], || "a");
+
+#[test]
+fn no_parameters_closure_test() {
+    assert_eq!(ARR87, [7, 7, 7]);
+    assert_eq!(ARR88, []);
+    assert_eq!(ARR89, ["a", "a"]);
+}
+
+#[allow(dead_code)]
+#[repr(u8)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    const COUNT: usize = Color::Blue as usize + 1;
+}
+
+const ARR90: [u32; Color::COUNT] = const_arr!([u32; Color::COUNT], |i| i as u32);
+const ARR91: [u32; Color::COUNT * 2] = const_arr!([u32; Color::COUNT * 2], |i| i as u32);
+
+#[test]
+fn associated_const_size_test() {
+    assert_eq!(ARR90, [0, 1, 2]);
+    assert_eq!(ARR90[Color::Green as usize], 1);
+    assert_eq!(ARR91.len(), 6);
+}
+
+const fn inv(n: usize) -> u32 {
+    1000 / n as u32
+}
+
+const ARR92: [u32; 5] = const_arr!([u32; 5], seed = 0, |i| 1000 / i as u32);
+const ARR93: [u32; 5] = const_arr!([u32; 5], seed = u32::MAX, inv);
+const ARR94: [u32; 1] = const_arr!([u32; 1], seed = 7, inv);
+
+#[test]
+fn seed_test() {
+    assert_eq!(ARR92, [0, 1000, 500, 333, 250]);
+    assert_eq!(ARR93, [u32::MAX, 1000, 500, 333, 250]);
+    assert_eq!(ARR94, [7]);
+}
+
+// Hand-written expansion of `const_arr!([u16; 5], |i| i as u16 * 3)`,
+// as produced by closure and path arms.
+const ARR95: [u16; 5] = {
+    const fn __init(i: usize, _: &'static ()) -> u16 {
+        i as u16 * 3
+    }
+    const fn __fill<const N: usize>(_: &'static ()) -> [u16; N] {
+        const __TEMP_ITEM: u16 = __init(0, &());
+        let mut arr: [u16; N] = [__TEMP_ITEM; N];
+        let mut ind = 1;
+        while ind + 1 < N {
+            arr[ind] = __init(ind, &());
+            arr[ind + 1] = __init(ind + 1, &());
+            ind += 2;
+        }
+        if ind < N {
+            arr[ind] = __init(ind, &());
+        }
+        arr
+    }
+    __fill::<5>(&())
+};
+const ARR96: [u16; 5] = const_arr!([u16; 5], |i| i as u16 * 3);
+
+#[test]
+fn expanded_code_test() {
+    assert_eq!(ARR95, [0, 3, 6, 9, 12]);
+    assert_eq!(ARR95, ARR96);
+}
+
+// `Option::unwrap` is const since Rust 1.83, so `match` is used to support older versions
+const fn non_zero(n: u32) -> NonZeroU32 {
+    match NonZeroU32::new(n) {
+        Some(n) => n,
+        None => panic!("value is 0"),
+    }
+}
+
+const ARR97: [NonZeroU32; 4] = const_arr!([core::num::NonZeroU32; 4], |i| non_zero(i as u32 + 1));
+const ARR98: [NonZeroU32; 4] = const_arr!([NonZeroU32; 4], seed = non_zero(1), |i| non_zero(i as u32 * 2));
+
+#[test]
+fn non_zero_items_test() {
+    assert_eq!(ARR97.map(NonZeroU32::get), [1, 2, 3, 4]);
+    assert_eq!(ARR98.map(NonZeroU32::get), [1, 2, 4, 6]);
+}
+
+const BASE: [u8; 3] = [10, 20, 30];
+
+const ARR99: [u8; 5] = const_arr!([u8; 5], |i| BASE[i % BASE.len()]);
+const ARR100: [u8; 2] = const_arr!([u8; 2], |i| BASE[i % BASE.len()]);
+const ARR101: [u8; 4] = const_arr!([u8; 4], |i, arr| {
+    if i < BASE.len() { BASE[i] } else { arr[i % BASE.len()] + 1 }
+});
+
+#[test]
+fn base_modulo_test() {
+    assert_eq!(ARR99, [10, 20, 30, 10, 20]);
+    assert_eq!(ARR100, [10, 20]);
+    assert_eq!(ARR101, [10, 20, 30, 11]);
+}
+
+const ARR102: [i32; 3] = const_arr!([i32; 3], fn(i) { i as i32 });
+const ARR103: [i32; 4] = const_arr!([i32; 4], fn(i) {
+    let odd = i % 2 == 1;
+    if odd { i as i32 } else { -1 }
+});
+const ARR104: [i32; 0] = const_arr!([i32; 0], fn(i) { 100 / i as i32 });
+
+#[test]
+fn fn_sugar_test() {
+    assert_eq!(ARR102, [0, 1, 2]);
+    assert_eq!(ARR103, [-1, 1, -1, 3]);
+    assert_eq!(ARR104, []);
+}
+
+macro_rules! Int {
+    () => {
+        i64
+    };
+    (unsigned) => {
+        u64
+    };
+}
+
+type Signed = Int!();
+
+const ARR105: [i64; 4] = const_arr!([Int!(); 4], |i| -(i as i64));
+const ARR106: [u64; 2] = const_arr!([Int!(unsigned); 2]; 3);
+const ARR107: [Signed; 3] = const_arr!([Signed; 3], |i| i as Signed);
+
+#[test]
+fn macro_type_test() {
+    assert_eq!(ARR105, [0, -1, -2, -3]);
+    assert_eq!(ARR106, [3, 3]);
+    assert_eq!(ARR107, [0, 1, 2]);
+}
+
+const ARR108: [Duration; 4] = const_arr!([Duration; 4], |i| Duration::from_secs(i as u64));
+
+#[test]
+fn duration_test() {
+    assert_eq!(ARR108, [
+        Duration::ZERO,
+        Duration::from_secs(1),
+        Duration::from_secs(2),
+        Duration::from_secs(3),
+    ]);
+}
+
+const ARR109: [Duration; 3] = const_arr!([Duration; 3], |i| Duration::from_millis(250 * (i as u64 + 1)));
+
+#[test]
+fn duration_millis_test() {
+    assert_eq!(ARR109[0].as_millis(), 250);
+    assert_eq!(ARR109[2].as_secs_f32(), 0.75);
+}
+
+const ROWS: usize = 3;
+const COLS: usize = 4;
+
+const fn build_row(row: usize) -> [u8; COLS] {
+    [row as u8; COLS]
+}
+
+const ARR110: [[u8; COLS]; ROWS] = const_arr!([[u8; COLS]; ROWS], build_row);
+
+#[test]
+fn row_fn_test() {
+    assert_eq!(ARR110, [[0; COLS], [1; COLS], [2; COLS]]);
+}
+
+const fn build_ramp_row(row: usize) -> [u8; COLS] {
+    let mut ramp: [u8; COLS] = const_arr!([u8; COLS], |col| col as u8);
+    ramp[0] = row as u8 * 10;
+    ramp
+}
+
+const ARR111: [[u8; COLS]; ROWS] = const_arr!([[u8; COLS]; ROWS], build_ramp_row);
+
+#[test]
+fn nested_row_fn_test() {
+    assert_eq!(ARR111, [[0, 1, 2, 3], [10, 1, 2, 3], [20, 1, 2, 3]]);
+}
+
+const ARR112: [[[u8; 2]; 2]; 2] = const_arr!([[[u8; 2]; 2]; 2], |i| [[i as u8; 2]; 2]);
+
+#[test]
+fn three_levels_test() {
+    assert_eq!(ARR112, [[[0, 0], [0, 0]], [[1, 1], [1, 1]]]);
+}
+
+const ARR113: [[[u8; 2]; 2]; 2] = const_arr!([[[u8; 2]; 2]; 2], |_| {
+    const_arr!([[u8; 2]; 2], |j| [j as u8; 2])
+});
+
+#[test]
+fn three_levels_nested_macro_test() {
+    assert_eq!(ARR113[0], [[0, 0], [1, 1]]);
+    assert_eq!(ARR113[1], ARR113[0]);
+}
+
+const ARR114: [[[[u16; 2]; 2]; 2]; 2] = const_arr!([[[[u16; 2]; 2]; 2]; 2]; [[[7; 2]; 2]; 2]);
+
+#[test]
+fn four_levels_value_test() {
+    assert_eq!(ARR114[1][1][1], [7, 7]);
+}
+
+const POOL: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+const OFFSETS: [usize; 3] = [0, 3, 7];
+
+// `split_at` is const only since Rust 1.71, so slices are cut with slice patterns
+const fn sub(pool: &'static [u8], start: usize, end: usize) -> &'static [u8] {
+    let mut slice = pool;
+    while slice.len() > pool.len() - start {
+        if let [_, rest @ ..] = slice {
+            slice = rest;
+        }
+    }
+    while slice.len() > end - start {
+        if let [rest @ .., _] = slice {
+            slice = rest;
+        }
+    }
+    slice
+}
+
+const ARR115: [&[u8]; 2] = const_arr!([&[u8]; 2], |i| sub(&POOL, OFFSETS[i], OFFSETS[i + 1]));
+
+#[test]
+fn pool_slices_test() {
+    assert_eq!(ARR115, [&[1, 2, 3][..], &[4, 5, 6, 7][..]]);
+}
+
+fn first_slice() -> &'static [u8] {
+    ARR115[0]
+}
+
+#[test]
+fn pool_slices_static_test() {
+    assert_eq!(first_slice(), &POOL[..3]);
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Point,
+    Circle { r: u32 },
+    Rect { w: u32, h: u32 },
+}
+
+const ARR116: [Shape; 4] = const_arr!([Shape; 4], |i| match i {
+    0 => Shape::Point,
+    1 => Shape::Circle { r: 5 },
+    _ => Shape::Rect { w: i as u32, h: 2 },
+});
+
+#[test]
+fn match_structs_test() {
+    assert_eq!(ARR116, [Shape::Point, Shape::Circle { r: 5 }, Shape::Rect { w: 2, h: 2 }, Shape::Rect { w: 3, h: 2 }]);
+}
+
+const ARR117: [&str; 6] = const_arr!([&str; 6], |i| match i {
+    0 => "zero",
+    n if n % 2 == 0 => "even",
+    _ => "odd",
+});
+
+#[test]
+fn match_guard_test() {
+    assert_eq!(ARR117, ["zero", "odd", "even", "odd", "even", "odd"]);
+}
+
+const OPTIONAL: [Option<u8>; 3] = [Some(7), None, Some(9)];
+
+const fn optional(i: usize) -> Option<u8> {
+    if i < OPTIONAL.len() { OPTIONAL[i] } else { None }
+}
+
+const ARR118: [u8; 4] = const_arr!([u8; 4], |i| match optional(i) {
+    Some(value) if value > 8 => value * 10,
+    Some(value) => value,
+    None => 0,
+});
+
+#[test]
+fn match_option_guard_test() {
+    assert_eq!(ARR118, [7, 0, 90, 0]);
+}
+
+const ARR119: [u8; 3] = const_arr!([u8; 3], |i| if let Some(value) = optional(i) { value } else { 1 });
+
+#[test]
+fn if_let_test() {
+    assert_eq!(ARR119, [7, 1, 9]);
+}
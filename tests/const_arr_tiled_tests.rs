@@ -0,0 +1,37 @@
+use const_array_init::const_arr_tiled;
+
+const ARR1: [usize; 7] = const_arr_tiled!([usize; 7], 3, |i, j| i * 10 + j);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR1, [0, 11, 22, 30, 41, 52, 60]);
+}
+
+const fn ramp(_i: usize, j: usize) -> u8 {
+    j as u8 * 50
+}
+
+const TILE: usize = 4;
+
+const ARR2: [u8; 9] = const_arr_tiled!([u8; 9], TILE, ramp);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [0, 50, 100, 150, 0, 50, 100, 150, 0]);
+}
+
+const ARR3: [usize; 3] = const_arr_tiled!([usize; 3], 1, |_i, j| j);
+const ARR4: [usize; 3] = const_arr_tiled!([usize; 3], 5, |_i, j| j);
+
+#[test]
+fn tile_len_test() {
+    assert_eq!(ARR3, [0, 0, 0]);
+    assert_eq!(ARR4, [0, 1, 2]);
+}
+
+const ARR5: [f32; 5] = const_arr_tiled!([f32; 5], 2, |_i, j| j as f32 * 0.5);
+
+#[test]
+fn float_body_test() {
+    assert_eq!(ARR5, [0.0, 0.5, 0.0, 0.5, 0.0]);
+}
@@ -0,0 +1,61 @@
+use const_array_init::const_arr_try;
+
+const fn parse_digit(b: u8) -> Result<u8, ()> {
+    match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        _ => Err(()),
+    }
+}
+
+const DIGITS: &[u8] = b"2718";
+
+const fn digit_at(n: usize) -> Result<u8, ()> {
+    parse_digit(DIGITS[n])
+}
+
+const ARR1: [u8; 4] = const_arr_try!([u8; 4], digit_at);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR1, [2, 7, 1, 8]);
+}
+
+const ARR2: [u8; 4] = const_arr_try!([u8; 4], |i| parse_digit(DIGITS[i]));
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, [2, 7, 1, 8]);
+}
+
+const ARR3: [u8; 4] = const_arr_try!([u8; 4], |i| -> Result<u8, ()> {
+    let byte = DIGITS[i];
+    parse_digit(byte)
+});
+
+#[test]
+fn closure_with_return_type_test() {
+    assert_eq!(ARR3, [2, 7, 1, 8]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct User {
+    id: u32,
+}
+
+const ARR4: [User; 3] = const_arr_try!([User; 3], |i| match i {
+    0..=2 => Ok(User { id: i as u32 }),
+    _ => Err("too many users"),
+});
+
+#[test]
+fn non_copy_closure_test() {
+    assert_eq!(ARR4, [User { id: 0 }, User { id: 1 }, User { id: 2 },]);
+}
+
+#[allow(non_snake_case)]
+const ARR5: [u8; 4] = const_arr_try!([u8; 4], |TEMP_ITEM| parse_digit(DIGITS[TEMP_ITEM]));
+
+#[test]
+fn internal_names_closure_test() {
+    assert_eq!(ARR5, [2, 7, 1, 8]);
+}
@@ -0,0 +1,35 @@
+use const_array_init::const_arr_try_verbose;
+
+const fn half(n: usize) -> Result<u8, &'static str> {
+    if n % 2 == 0 {
+        Ok(n as u8 / 2)
+    } else {
+        Err("odd")
+    }
+}
+
+const ARR1: [u8; 3] = const_arr_try_verbose!([u8; 3], |i| half(i * 2));
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR1, [0, 1, 2]);
+}
+
+const fn checked(n: usize) -> Result<u16, &'static str> {
+    if n < 100 { Ok(n as u16 * 10) } else { Err("too big") }
+}
+
+const ARR2: [u16; 4] = const_arr_try_verbose!([u16; 4], checked);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2, [0, 10, 20, 30]);
+}
+
+// Failing index is searched only below SIZE
+const ARR3: [u8; 3] = const_arr_try_verbose!([u8; 3], |i| if i < 3 { Ok(i as u8) } else { Err("too big") });
+
+#[test]
+fn last_index_test() {
+    assert_eq!(ARR3, [0, 1, 2]);
+}
@@ -0,0 +1,57 @@
+use const_array_init::const_arr_until;
+
+const ARR1: [i32; 5] = const_arr_until!([i32; 5], -1, |i| if i < 2 { Some(i as i32 + 1) } else { None });
+
+#[test]
+fn default_tail_test() {
+    assert_eq!(ARR1, [1, 2, -1, -1, -1]);
+}
+
+const fn stop_at_two(i: usize) -> Option<u8> {
+    if i == 2 {
+        None
+    } else {
+        Some(10 + i as u8)
+    }
+}
+
+const ARR2: [u8; 5] = const_arr_until!([u8; 5], 0, stop_at_two);
+
+#[test]
+fn stops_calling_test() {
+    // Index 3 would return Some(13), but init function isn't called after first None
+    assert_eq!(ARR2, [10, 11, 0, 0, 0]);
+}
+
+const ARR3: [u16; 3] = const_arr_until!([u16; 3], 0, |i| Some(i as u16));
+
+#[test]
+fn no_none_test() {
+    assert_eq!(ARR3, [0, 1, 2]);
+}
+
+#[derive(Debug, PartialEq)]
+struct Slot {
+    id: u32,
+}
+
+const ARR4: [Slot; 3] = const_arr_until!([Slot; 3], Slot { id: 0 }, |i| if i == 0 { Some(Slot { id: 7 }) } else { None });
+
+#[test]
+fn non_copy_test() {
+    assert_eq!(ARR4, [Slot { id: 7 }, Slot { id: 0 }, Slot { id: 0 }]);
+}
+
+const ARR5: [&str; 3] = const_arr_until!([&str; 3], "", |i| if i == 0 { Some("first") } else { None });
+
+#[test]
+fn str_test() {
+    assert_eq!(ARR5, ["first", "", ""]);
+}
+
+const ARR6: [f32; 4] = const_arr_until!([f32; 4], -1.0, |i| if i < 2 { Some(i as f32 / 2.0) } else { None });
+
+#[test]
+fn float_body_test() {
+    assert_eq!(ARR6, [0.0, 0.5, -1.0, -1.0]);
+}
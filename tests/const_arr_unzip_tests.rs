@@ -0,0 +1,31 @@
+use const_array_init::const_arr_unzip;
+
+const PAIR1: ([usize; 3], [usize; 3]) = const_arr_unzip!([usize; 3], [usize; 3], |i| (i, i * 2));
+
+#[test]
+fn unzip_test() {
+    assert_eq!(PAIR1, ([0, 1, 2], [0, 2, 4]));
+}
+
+const fn name_and_len(i: usize) -> (&'static str, usize) {
+    const NAMES: [&str; 3] = ["one", "two", "three"];
+    (NAMES[i], NAMES[i].len())
+}
+
+const PAIR2: ([&str; 3], [usize; 3]) = const_arr_unzip!([&str; 3], [usize; 3], name_and_len);
+
+#[test]
+fn function_test() {
+    assert_eq!(PAIR2.0, ["one", "two", "three"]);
+    assert_eq!(PAIR2.1, [3, 3, 5]);
+}
+
+const N: usize = 4;
+
+const PAIR3: ([bool; N], [i8; N]) = const_arr_unzip!([bool; N], [i8; N], |i| (i % 2 == 0, -(i as i8)));
+
+#[test]
+fn different_types_test() {
+    assert_eq!(PAIR3.0, [true, false, true, false]);
+    assert_eq!(PAIR3.1, [0, -1, -2, -3]);
+}
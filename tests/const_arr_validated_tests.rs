@@ -0,0 +1,46 @@
+use const_array_init::{const_arr_validated, ValidationError};
+
+const fn all_even(arr: &[u32]) -> bool {
+    let mut i = 0;
+    while i < arr.len() {
+        if arr[i] % 2 != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const RES1: Result<[u32; 4], ValidationError> = const_arr_validated!([u32; 4], |i| i as u32 * 2, |arr| all_even(arr));
+
+const RES2: Result<[u32; 4], ValidationError> = const_arr_validated!([u32; 4], |i| i as u32, |arr| all_even(arr));
+
+#[test]
+fn closure_test() {
+    assert_eq!(RES1, Ok([0, 2, 4, 6]));
+    assert_eq!(RES2, Err(ValidationError));
+}
+
+const fn square(n: usize) -> u32 {
+    (n * n) as u32
+}
+
+const RES3: Result<[u32; 3], ValidationError> = const_arr_validated!([u32; 3], square, |arr| arr[2] == 4);
+
+const RES4: Result<[u32; 3], ValidationError> = const_arr_validated!([u32; 3], square, |arr| arr.len() > 3);
+
+#[test]
+fn function_test() {
+    assert_eq!(RES3, Ok([0, 1, 4]));
+    assert_eq!(RES4, Err(ValidationError));
+}
+
+const ARR: [u32; 2] = match const_arr_validated!([u32; 2], |i| i as u32 + 1, |arr| arr[0] < arr[1]) {
+    Ok(arr) => arr,
+    Err(_) => panic!("ARR is not increasing"),
+};
+
+#[test]
+fn unwrap_in_const_test() {
+    assert_eq!(ARR, [1, 2]);
+}
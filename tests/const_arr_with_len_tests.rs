@@ -0,0 +1,44 @@
+use const_array_init::const_arr_with_len;
+
+const RAMP: [f32; 3] = const_arr_with_len!([f32; 3], |i, len| i as f32 / len as f32);
+
+#[test]
+fn ramp_test() {
+    assert_eq!(RAMP, [0.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0]);
+}
+
+const N: usize = 4;
+
+const REVERSED: [usize; N] = const_arr_with_len!([usize; N], |i, len| len - 1 - i);
+
+#[test]
+fn const_size_test() {
+    assert_eq!(REVERSED, [3, 2, 1, 0]);
+}
+
+// Float arithmetic in `const fn` requires Rust 1.82, so integer percent is used
+const fn percent(i: usize, len: usize) -> u8 {
+    (i * 100 / len) as u8
+}
+
+const PERCENT: [u8; N] = const_arr_with_len!([u8; N], percent);
+
+#[test]
+fn const_fn_test() {
+    assert_eq!(PERCENT, [0, 25, 50, 75]);
+}
+
+struct Ramp;
+
+impl Ramp {
+    const fn down(i: usize, len: usize) -> u8 {
+        (len - i) as u8
+    }
+}
+
+const DOWN: [u8; 3] = const_arr_with_len!([u8; 3], Ramp::down);
+
+#[test]
+fn associated_fn_test() {
+    assert_eq!(DOWN, [3, 2, 1]);
+}
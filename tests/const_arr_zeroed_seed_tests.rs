@@ -0,0 +1,66 @@
+use const_array_init::const_arr_zeroed_seed;
+
+const fn div_2(n: usize) -> i32 {
+    n as i32 / 2
+}
+
+const ARR1: [i32; 4] = const_arr_zeroed_seed!([i32; 4], div_2);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR1, [0, 0, 1, 1]);
+}
+
+const ARR2: [f64; 3] = const_arr_zeroed_seed!([f64; 3], |i| i as f64 * 0.5);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, [0.0, 0.5, 1.0]);
+}
+
+#[test]
+fn overwrites_index_0_test() {
+    const ARR: [u8; 3] = const_arr_zeroed_seed!([u8; 3], |i| i as u8 + 7);
+    assert_eq!(ARR, [7, 8, 9]);
+}
+
+const fn panics_at_0(n: usize) -> u8 {
+    if n == 0 {
+        panic!("called for index 0")
+    }
+    n as u8
+}
+
+// Init function isn't called for indices before `start`, so they keep zero seed
+const ARR3: [u8; 4] = const_arr_zeroed_seed!([u8; 4], start = 1, panics_at_0);
+const ARR4: [u8; 3] = const_arr_zeroed_seed!([u8; 3], start = 1, |i| 10 / i as u8);
+const ARR5: [i16; 5] = const_arr_zeroed_seed!([i16; 5], start = 3, |i| -(i as i16));
+
+#[test]
+fn start_test() {
+    assert_eq!(ARR3, [0, 1, 2, 3]);
+    assert_eq!(ARR4, [0, 10, 5]);
+    assert_eq!(ARR5, [0, 0, 0, -3, -4]);
+}
+
+// Init function is called only while filling the array, and there is no seed
+// `const`, which would call it for index 0 even if array is empty
+const fn generic_panics_at_0<const N: usize>() -> [u8; N] {
+    const_arr_zeroed_seed!([u8; N], panics_at_0)
+}
+
+#[test]
+fn no_seed_call_test() {
+    const ARR: [u8; 0] = generic_panics_at_0::<0>();
+    assert_eq!(ARR, []);
+}
+
+const fn generic_size<const N: usize>() -> [u16; N] {
+    const_arr_zeroed_seed!([u16; N], |i| i as u16 + 1)
+}
+
+#[test]
+fn generic_size_test() {
+    const ARR: [u16; 3] = generic_size();
+    assert_eq!(ARR, [1, 2, 3]);
+}
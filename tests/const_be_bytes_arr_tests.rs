@@ -0,0 +1,24 @@
+use const_array_init::{const_arr, const_be_bytes_arr};
+
+const MAGIC: u32 = 0xDEAD_BEEF;
+
+const BYTES1: [u8; 4] = const_be_bytes_arr!(MAGIC);
+
+#[test]
+fn u32_test() {
+    assert_eq!(BYTES1, [0xDE, 0xAD, 0xBE, 0xEF]);
+}
+
+const BYTES2: [u8; 8] = const_be_bytes_arr!(-2_i64);
+
+#[test]
+fn i64_test() {
+    assert_eq!(BYTES2, [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+}
+
+const BYTES3: [u8; 4] = const_arr!([u8; 4], |i| MAGIC.to_be_bytes()[i]);
+
+#[test]
+fn const_arr_index_test() {
+    assert_eq!(BYTES3, BYTES1);
+}
@@ -0,0 +1,36 @@
+use const_array_init::{const_arr, const_concat_arr, const_subarr};
+
+const LEFT: [u8; 2] = [1, 2];
+const RIGHT: [u8; 3] = [3, 4, 5];
+const EMPTY: [u8; 0] = [];
+
+const ARR1: [u8; 5] = const_concat_arr!([u8; 2 + 3], LEFT, RIGHT);
+
+#[test]
+fn concat_test() {
+    assert_eq!(ARR1, [1, 2, 3, 4, 5]);
+}
+
+const ARR2: [u8; 3] = const_concat_arr!([u8; 3], EMPTY, RIGHT);
+const ARR3: [u8; 2] = const_concat_arr!([u8; 2], LEFT, EMPTY);
+
+#[test]
+fn empty_test() {
+    assert_eq!(ARR2, [3, 4, 5]);
+    assert_eq!(ARR3, [1, 2]);
+}
+
+const A: usize = 4;
+const B: usize = 2;
+
+const SQUARES: [u32; A] = const_arr!([u32; A], |i| (i * i) as u32);
+const ONES: [u32; B] = const_arr!([u32; B]; 1);
+
+const ARR4: [u32; A + B] = const_concat_arr!([u32; A + B], SQUARES, ONES);
+const ARR5: [u32; 3] = const_concat_arr!([u32; 3], ONES, const_subarr!(SQUARES, 3, 1));
+
+#[test]
+fn named_size_test() {
+    assert_eq!(ARR4, [0, 1, 4, 9, 1, 1]);
+    assert_eq!(ARR5, [1, 1, 9]);
+}
@@ -0,0 +1,30 @@
+use const_array_init::{const_arr, const_interleave_arr};
+
+const LEFT: [u8; 2] = [1, 2];
+const RIGHT: [u8; 2] = [3, 4];
+
+const ARR1: [u8; 4] = const_interleave_arr!([u8; 4], LEFT, RIGHT);
+
+#[test]
+fn interleave_test() {
+    assert_eq!(ARR1, [1, 3, 2, 4]);
+}
+
+const N: usize = 3;
+
+const EVENS: [i16; N] = const_arr!([i16; N], |i| (i * 2) as i16);
+const ODDS: [i16; N] = const_arr!([i16; N], |i| (i * 2 + 1) as i16);
+
+const ARR2: [i16; 2 * N] = const_interleave_arr!([i16; 2 * N], EVENS, ODDS);
+
+#[test]
+fn named_size_test() {
+    assert_eq!(ARR2, [0, 1, 2, 3, 4, 5]);
+}
+
+const ARR3: [char; 2] = const_interleave_arr!([char; 2], ['a'], ['b']);
+
+#[test]
+fn single_item_test() {
+    assert_eq!(ARR3, ['a', 'b']);
+}
@@ -0,0 +1,25 @@
+use const_array_init::{const_arr, const_le_bytes_arr};
+
+const MAGIC: u32 = 0xDEAD_BEEF;
+
+const BYTES1: [u8; 4] = const_le_bytes_arr!(MAGIC);
+
+#[test]
+fn u32_test() {
+    assert_eq!(BYTES1, [0xEF, 0xBE, 0xAD, 0xDE]);
+}
+
+const BYTES2: [u8; 2] = const_le_bytes_arr!(0x0102_u16);
+
+#[test]
+fn u16_test() {
+    assert_eq!(BYTES2, [0x02, 0x01]);
+}
+
+// Indexing result of `to_le_bytes` works both for seed at index 0 and in loop
+const BYTES3: [u8; 4] = const_arr!([u8; 4], |i| MAGIC.to_le_bytes()[i]);
+
+#[test]
+fn const_arr_index_test() {
+    assert_eq!(BYTES3, BYTES1);
+}
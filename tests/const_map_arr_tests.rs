@@ -0,0 +1,36 @@
+use const_array_init::{const_map_arr, make_const_map_arr};
+
+const SRC: [u8; 4] = [1, 2, 3, 250];
+
+const ARR1: [u16; 4] = const_map_arr!(SRC, |x| x as u16 * 2);
+
+#[test]
+fn inferred_type_test() {
+    assert_eq!(ARR1, [2, 4, 6, 500]);
+}
+
+const ARR2: [u16; 4] = const_map_arr!([u16; 4], SRC, |x| x as u16 * 2);
+
+#[test]
+fn explicit_type_test() {
+    assert_eq!(ARR2, [2, 4, 6, 500]);
+}
+
+make_const_map_arr!(ARR3, [u16; 4], SRC, |x| x as u16 * 2);
+
+#[test]
+fn make_test() {
+    assert_eq!(ARR3, [2, 4, 6, 500]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct User {
+    id: u32,
+}
+
+make_const_map_arr!(USERS, [User; 4], SRC, |x| User { id: x as u32 });
+
+#[test]
+fn non_copy_make_test() {
+    assert_eq!(USERS, [User { id: 1 }, User { id: 2 }, User { id: 3 }, User { id: 250 }]);
+}
@@ -0,0 +1,38 @@
+use const_array_init::{const_arr, const_subarr};
+
+const SRC: [u32; 8] = const_arr!([u32; 8], |i| i as u32 * 10);
+
+const DST1: [u32; 3] = const_subarr!(SRC, 0, 3);
+
+const DST2: [u32; 3] = const_subarr!(SRC, 5, 3);
+
+const DST3: [u32; 8] = const_subarr!(SRC, 0, SRC.len());
+
+#[test]
+fn subarr_test() {
+    assert_eq!(DST1, [0, 10, 20]);
+    assert_eq!(DST2, [50, 60, 70]);
+    assert_eq!(DST3, SRC);
+}
+
+const BYTES: &[u8] = b"const-array-init";
+
+const NAME: [u8; 5] = const_subarr!(BYTES, 0, 5);
+
+#[test]
+fn slice_source_test() {
+    assert_eq!(&NAME, b"const");
+}
+
+const DST4: [u32; 0] = const_subarr!(SRC, 8, 0);
+const DST5: [u32; 0] = const_subarr!(SRC, SRC.len(), SRC.len() - 8);
+
+const NONE: [u32; 0] = [];
+const DST6: [u32; 0] = const_subarr!(NONE, 0, 0);
+
+#[test]
+fn empty_at_end_test() {
+    assert_eq!(DST4, []);
+    assert_eq!(DST5, []);
+    assert_eq!(DST6, []);
+}
@@ -0,0 +1,38 @@
+use const_array_init::const_zip_arr;
+
+const IDS: [i32; 3] = [1, 2, 3];
+const NAMES: [&str; 3] = ["one", "two", "three"];
+
+const ARR1: [(i32, &str); 3] = const_zip_arr!(IDS, NAMES, |a, b| (a, b));
+
+#[test]
+fn inferred_type_test() {
+    assert_eq!(ARR1, [(1, "one"), (2, "two"), (3, "three")]);
+}
+
+const ARR2: [usize; 3] = const_zip_arr!([usize; 3], IDS, NAMES, |id, name| id as usize + name.len());
+
+#[test]
+fn explicit_type_test() {
+    assert_eq!(ARR2, [4, 5, 8]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct User {
+    id: i32,
+    name: &'static str,
+}
+
+const USERS: [User; 3] = const_zip_arr!([User; 3], IDS, NAMES, |id, name| User { id, name });
+
+#[test]
+fn non_copy_test() {
+    assert_eq!(
+        USERS,
+        [
+            User { id: 1, name: "one" },
+            User { id: 2, name: "two" },
+            User { id: 3, name: "three" },
+        ]
+    );
+}
@@ -0,0 +1,114 @@
+#![deny(warnings, unused, nonstandard_style, rust_2018_idioms)]
+
+use const_array_init::{
+    const_arr, const_arr_generic, const_arr_seeded, const_arr_zeroed_seed, const_map_arr, make_const_arr,
+    make_static_arr,
+};
+
+const fn to_u8(n: usize) -> u8 {
+    n as u8
+}
+
+const fn add(n: usize, k: u8) -> u8 {
+    n as u8 + k
+}
+
+struct Idx(usize);
+
+const ARR1: [u8; 1] = const_arr!([u8; 1], to_u8);
+const ARR2: [u8; 1] = const_arr!([u8; 1], |i| i as u8);
+const ARR3: [u8; 1] = const_arr!([u8; 1], |_| 5);
+const ARR4: [u8; 1] = const_arr!([u8; 1]; 5);
+const ARR5: [u8; 1] = const_arr!([u8; 1], |i, arr| if i == 0 { 1 } else { arr[i - 1] });
+const ARR6: [u8; 1] = const_arr!([u8; 1], |i: u8| i);
+const ARR7: [u8; 1] = const_arr!([u8; 1], |Idx(i)| i as u8);
+const ARR8: [u8; 1] = const_arr!([u8; 1], add, 3);
+const ARR9: [u8; 1] = const_arr!([_; 1], |i| -> u8 { i as u8 });
+
+#[test]
+fn size_1_test() {
+    assert_eq!(ARR1, [0]);
+    assert_eq!(ARR2, [0]);
+    assert_eq!(ARR3, [5]);
+    assert_eq!(ARR4, [5]);
+    assert_eq!(ARR5, [1]);
+    assert_eq!(ARR6, [0]);
+    assert_eq!(ARR7, [0]);
+    assert_eq!(ARR8, [3]);
+    assert_eq!(ARR9, [0]);
+}
+
+const ARR10: [u8; 0] = const_arr!([u8; 0], to_u8);
+const ARR11: [u8; 0] = const_arr!([u8; 0], |i| i as u8);
+const ARR12: [u8; 0] = const_arr!([u8; 0], |_| 5);
+const ARR13: [u8; 0] = const_arr!([u8; 0]; 5);
+const ARR14: [u8; 0] = const_arr!([u8; 0], |i, arr| if i == 0 { 1 } else { arr[i - 1] });
+const ARR15: [u8; 0] = const_arr!([u8; 0], |i: u8| i);
+const ARR16: [u8; 0] = const_arr!([u8; 0], |Idx(i)| i as u8);
+const ARR17: [u8; 0] = const_arr!([u8; 0], add, 3);
+
+#[test]
+fn size_0_test() {
+    assert_eq!(ARR10, []);
+    assert_eq!(ARR11, []);
+    assert_eq!(ARR12, []);
+    assert_eq!(ARR13, []);
+    assert_eq!(ARR14, []);
+    assert_eq!(ARR15, []);
+    assert_eq!(ARR16, []);
+    assert_eq!(ARR17, []);
+}
+
+make_const_arr!(ARR18, [u8; 3], |i| i as u8);
+make_const_arr!(ARR19, [u8; 3], |_| 1);
+make_static_arr!(ARR20, [u8; 3], to_u8);
+
+#[test]
+fn make_arr_test() {
+    assert_eq!(ARR18, [0, 1, 2]);
+    assert_eq!(ARR19, [1, 1, 1]);
+    assert_eq!(ARR20, [0, 1, 2]);
+}
+
+#[test]
+fn let_binding_test() {
+    let arr1 = const_arr!([u8; 1], |i| i as u8);
+    let arr2 = const_arr!([u8; 3]; 7);
+    let arr3 = const_arr!([u8; 2], |i, arr| if i == 0 { 1 } else { arr[0] + 1 });
+    assert_eq!(arr1, [0]);
+    assert_eq!(arr2, [7, 7, 7]);
+    assert_eq!(arr3, [1, 2]);
+}
+
+const fn generic_table<const N: usize>() -> [u8; N] {
+    const_arr!([u8; N], |i| i as u8)
+}
+
+const ARR21: [u8; 1] = generic_table::<1>();
+const ARR22: [u8; 1] = const_arr_generic!([u8; 1], |i| i as u8);
+const ARR23: [u8; 1] = const_arr_seeded!([u8; 1], 9, |i| i as u8);
+const ARR24: [u8; 1] = const_arr_zeroed_seed!([u8; 1], |i| i as u8);
+const ARR25: [u8; 1] = const_map_arr!(ARR24, |x| x + 1);
+
+#[test]
+fn other_macros_test() {
+    assert_eq!(ARR21, [0]);
+    assert_eq!(ARR22, [0]);
+    assert_eq!(ARR23, [9]);
+    assert_eq!(ARR24, [0]);
+    assert_eq!(ARR25, [1]);
+}
+
+#[test]
+fn make_arr_in_fn_body_test() {
+    // Expansion in statement position must not leave empty statements
+    // behind, which would be reported as redundant semicolons.
+    #![deny(redundant_semicolons)]
+    make_const_arr!(FN_ARR, [u8; 3], to_u8);
+    make_const_arr!(CLOSURE_ARR, [u8; 3], |i| to_u8(i));
+    make_const_arr!(TRAILING_COMMA_ARR, [u8; 3], to_u8,);
+    make_static_arr!(STATIC_ARR, [u8; 3], to_u8);
+    assert_eq!(FN_ARR, CLOSURE_ARR);
+    assert_eq!(FN_ARR, TRAILING_COMMA_ARR);
+    assert_eq!(FN_ARR, STATIC_ARR);
+}
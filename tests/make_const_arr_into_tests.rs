@@ -0,0 +1,52 @@
+use const_array_init::make_const_arr_into;
+
+struct Buffer<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    const fn from_array(data: [u8; N]) -> Self {
+        Buffer { data, len: N }
+    }
+
+    const fn with_len(data: [u8; N]) -> Self {
+        Buffer { data, len: N / 2 }
+    }
+}
+
+make_const_arr_into!(BUF1, Buffer<4>, [u8; 4], Buffer::from_array, |i| i as u8 + 1);
+
+#[test]
+fn closure_test() {
+    assert_eq!(BUF1.data, [1, 2, 3, 4]);
+    assert_eq!(BUF1.len, 4);
+}
+
+const fn square(n: usize) -> u8 {
+    (n * n) as u8
+}
+
+make_const_arr_into!(BUF2, Buffer<4>, [u8; 4], Buffer::<4>::with_len, square);
+
+#[test]
+fn function_test() {
+    assert_eq!(BUF2.data, [0, 1, 4, 9]);
+    assert_eq!(BUF2.len, 2);
+}
+
+make_const_arr_into!(BUF3, Buffer<3>, [u8; 3], Buffer::from_array; 0xFF);
+
+#[test]
+fn fill_test() {
+    assert_eq!(BUF3.data, [0xFF, 0xFF, 0xFF]);
+}
+
+pub struct Wrapper([i8; 2]);
+
+make_const_arr_into!(pub WRAPPER, Wrapper, [i8; 2], Wrapper, |i| -(i as i8));
+
+#[test]
+fn tuple_struct_test() {
+    assert_eq!(WRAPPER.0, [0, -1]);
+}
@@ -0,0 +1,23 @@
+use const_array_init::make_const_arr_like;
+
+const OTHER: [u8; 4] = [1, 2, 3, 4];
+
+make_const_arr_like!(ARR1, [u16; _], OTHER, |i| OTHER[i] as u16 + 256);
+
+#[test]
+fn other_type_test() {
+    assert_eq!(ARR1, [257, 258, 259, 260]);
+}
+
+const fn to_i8(n: usize) -> i8 {
+    -(n as i8)
+}
+
+make_const_arr_like!(pub ARR2, [i8; _], OTHER, to_i8);
+make_const_arr_like!(ARR3, [char; _], OTHER; 'x');
+
+#[test]
+fn function_and_value_test() {
+    assert_eq!(ARR2, [0, -1, -2, -3]);
+    assert_eq!(ARR3, ['x'; 4]);
+}
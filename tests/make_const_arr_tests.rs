@@ -1,3 +1,5 @@
+#![forbid(non_upper_case_globals)]
+
 use const_array_init::make_const_arr;
 
 make_const_arr!(ARR1, [i32; 3], div_2);
@@ -34,7 +36,7 @@ fn advanced_closure_test() {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct User {
+pub struct User {
     id: u32,
 }
 
@@ -45,7 +47,7 @@ fn super_advanced_closure_test() {
     assert_eq!(ARR5, [User { id: 0 }, User { id: 1 }, User { id: 2 },]);
 }
 
-make_const_arr!(ARR6, [User; 3], |_| User { id: 1 as u32 });
+make_const_arr!(#[allow(clippy::unnecessary_cast)] ARR6, [User; 3], |_| User { id: 1 as u32 });
 
 #[test]
 fn super_advanced_closure_test_empty_closure() {
@@ -63,3 +65,375 @@ make_const_arr!(ARR7, [User; 3], create_user);
 fn super_advanced_func_test() {
     assert_eq!(ARR7, [User { id: 0 }, User { id: 1 }, User { id: 2 },]);
 }
+
+const N: usize = 8;
+
+make_const_arr!(ARR8, [usize; N], |i| i * 2);
+
+#[test]
+fn const_size_test() {
+    assert_eq!(ARR8, [0, 2, 4, 6, 8, 10, 12, 14]);
+}
+
+make_const_arr!(ARR9, [i32; N / 2], div_2);
+
+#[test]
+fn const_expr_size_test() {
+    assert_eq!(ARR9, [0, 0, 1, 1]);
+}
+
+// Sum of 0..=n computed with a loop, so index 0 should only be paid for once
+const fn triangular(n: usize) -> u64 {
+    let mut sum = 0;
+    let mut k = 0;
+    while k <= n {
+        sum += k as u64;
+        k += 1;
+    }
+    sum
+}
+
+make_const_arr!(ARR10, [u64; 4], triangular);
+
+#[test]
+fn seed_index_func_test() {
+    assert_eq!(ARR10[0], 0);
+    assert_eq!(ARR10, [0, 1, 3, 6]);
+}
+
+make_const_arr!(ARR11, [i32; 5], |i, arr| {
+    if i == 0 {
+        1
+    } else {
+        arr[i - 1] + i as i32 + 1
+    }
+});
+
+#[test]
+fn cumulative_closure_test() {
+    assert_eq!(ARR11, [1, 3, 6, 10, 15]);
+}
+
+mod tables {
+    use super::div_2;
+    use const_array_init::make_const_arr;
+
+    make_const_arr!(pub PUB_ARR, [i32; 3], |i| i as i32 / 2);
+
+    make_const_arr!(pub(crate) CRATE_ARR, [i32; 3], div_2);
+
+    make_const_arr!(PRIVATE_ARR, [i32; 3], |_| 4 / 2);
+
+    pub fn private_arr() -> [i32; 3] {
+        PRIVATE_ARR
+    }
+}
+
+use tables::{CRATE_ARR, PUB_ARR};
+
+#[test]
+fn visibility_test() {
+    assert_eq!(PUB_ARR, [0, 0, 1]);
+    assert_eq!(CRATE_ARR, [0, 0, 1]);
+    assert_eq!(tables::private_arr(), [2, 2, 2]);
+}
+
+make_const_arr!(
+    /// Documented array
+    #[allow(dead_code)]
+    pub UNUSED_ARR, [i32; 3], |i| i as i32
+);
+
+make_const_arr!(#[cfg(test)] TEST_ONLY_ARR, [i32; 3], div_2);
+
+make_const_arr!(#[cfg(not(test))] TEST_ONLY_ARR, [i32; 3], |_| compile_error!("cfg was ignored"));
+
+#[test]
+fn attributes_test() {
+    assert_eq!(TEST_ONLY_ARR, [0, 0, 1]);
+}
+
+make_const_arr!(ARR13, [u8; 256], |b: u8| b.wrapping_mul(3));
+
+#[test]
+fn typed_index_closure_test() {
+    assert_eq!(ARR13[0], 0);
+    assert_eq!(ARR13[85], 255);
+    assert_eq!(ARR13[86], 2);
+    assert_eq!(ARR13[255], 253);
+}
+
+make_const_arr!(ARR14, [i32; 3], |arr| arr as i32 / 2);
+
+make_const_arr!(ARR15, [i32; 3], |ind| ind as i32 / 2);
+
+make_const_arr!(#[allow(non_snake_case)] ARR16, [i32; 3], |TEMP_ITEM| TEMP_ITEM as i32 / 2);
+
+#[test]
+fn internal_names_closure_test() {
+    assert_eq!(ARR14, [0, 0, 1]);
+    assert_eq!(ARR15, [0, 0, 1]);
+    assert_eq!(ARR16, [0, 0, 1]);
+}
+
+make_const_arr!(ARR19, [i32; 3], |i| i as i32 / 2,);
+
+make_const_arr!(ARR20, [i32; 3], div_2,);
+
+make_const_arr!(ARR21, [i32; 3], |_| 4 / 2,);
+
+#[test]
+fn trailing_comma_test() {
+    assert_eq!(ARR19, [0, 0, 1]);
+    assert_eq!(ARR20, [0, 0, 1]);
+    assert_eq!(ARR21, [2, 2, 2]);
+}
+
+make_const_arr!(ARR22, [u8; 16]; 0xFF);
+
+#[test]
+fn fill_test() {
+    assert_eq!(ARR22, [0xFF; 16]);
+}
+
+make_const_arr!(ARR23, [User; 3]; User { id: 7 });
+
+#[test]
+fn non_copy_fill_test() {
+    assert_eq!(ARR23, [User { id: 7 }, User { id: 7 }, User { id: 7 }]);
+}
+
+const fn mul<const K: u32>(n: usize) -> u32 {
+    n as u32 * K
+}
+
+make_const_arr!(ARR24, [u32; 4], mul::<3>);
+
+#[test]
+fn generic_function_test() {
+    assert_eq!(ARR24, [0, 3, 6, 9]);
+}
+
+impl User {
+    const fn new(n: usize) -> User {
+        User { id: n as u32 }
+    }
+}
+
+make_const_arr!(ARR25, [User; 3], User::new);
+
+#[test]
+fn path_function_test() {
+    assert_eq!(ARR25, [User { id: 0 }, User { id: 1 }, User { id: 2 }]);
+}
+
+make_const_arr!(ARR27, [i32; 3], |i| -> i32 { i as i32 / 2 });
+
+make_const_arr!(ARR28, [_; 3], |i| -> i32 { i as i32 / 2 });
+
+#[test]
+fn return_type_closure_test() {
+    assert_eq!(ARR27, [0, 0, 1]);
+    assert_eq!(ARR28, [0, 0, 1]);
+}
+
+make_const_arr!(ARR29, _, 3, |i| -> i32 { i as i32 / 2 });
+
+make_const_arr!(pub ARR30, _, N, |i| -> User { User { id: i as u32 } });
+
+#[test]
+fn inferred_type_closure_test() {
+    assert_eq!(ARR29, [0, 0, 1]);
+    assert_eq!(ARR30.len(), N);
+    assert_eq!(ARR30[7], User { id: 7 });
+}
+
+const fn invalid_at_0(n: usize) -> i32 {
+    100 / n as i32
+}
+
+make_const_arr!(ARR31, [i32; 0], invalid_at_0);
+
+make_const_arr!(ARR32, [i32; 0], |i| 100 / i as i32);
+
+make_const_arr!(ARR33, [_; 0], |i| -> u64 { 1 << (64 - i) });
+
+make_const_arr!(ARR34, [i32; 0]; invalid_at_0(0));
+
+#[test]
+fn zero_length_test() {
+    assert_eq!(ARR31, []);
+    assert_eq!(ARR32, []);
+    assert_eq!(ARR33, []);
+    assert_eq!(ARR34, []);
+}
+
+type Id = u32;
+
+type Users = [User; 2];
+
+const LEN: usize = 4;
+
+make_const_arr!(ARR35, [Id; LEN], |i| i as Id * 2);
+
+make_const_arr!(ARR36, [Users; 2], |i| [User { id: i as Id }, User { id: i as Id + 1 }]);
+
+make_const_arr!(ARR37, _, LEN, |i| -> Id { i as Id + 1 });
+
+#[test]
+fn type_alias_test() {
+    assert_eq!(ARR35, [0, 2, 4, 6]);
+    assert_eq!(ARR36, [[User { id: 0 }, User { id: 1 }], [User { id: 1 }, User { id: 2 }]]);
+    assert_eq!(ARR37, [1, 2, 3, 4]);
+}
+
+make_const_arr!(ARR38: [i32; 3] = |i| i as i32 / 2);
+
+make_const_arr!(ARR39: [i32; 3] = div_2);
+
+make_const_arr!(ARR40: [i32; 3] = |_| 4 / 2);
+
+make_const_arr!(ARR41: [_; 3] = |i| -> u64 { 1 << (i * 8) });
+
+make_const_arr!(
+    /// Users with ids from 1
+    #[allow(dead_code)]
+    pub(crate) ARR42: [User; 2] = |i| User { id: i as u32 + 1 },
+);
+
+#[test]
+fn declaration_syntax_test() {
+    assert_eq!(ARR38, [0, 0, 1]);
+    assert_eq!(ARR39, [0, 0, 1]);
+    assert_eq!(ARR40, [2, 2, 2]);
+    assert_eq!(ARR41, [1, 256, 65536]);
+    assert_eq!(ARR42, [User { id: 1 }, User { id: 2 }]);
+}
+
+const fn affine(n: usize, factor: i32, offset: i32) -> i32 {
+    n as i32 * factor + offset
+}
+
+make_const_arr!(ARR43, [i32; 4], affine, 2, 1);
+
+#[test]
+fn extra_arguments_test() {
+    assert_eq!(ARR43, [1, 3, 5, 7]);
+}
+
+make_const_arr!(ARR44, [u8; 0x100], |i| i as u8);
+
+make_const_arr!(ARR45, [u8; 1_024], |i| i as u8);
+
+make_const_arr!(ARR46, [u8; 256usize], |i| i as u8);
+
+#[test]
+fn size_literal_test() {
+    assert_eq!(ARR44.len(), 256);
+    assert_eq!(ARR45.len(), 1024);
+    assert_eq!(ARR46.len(), 256);
+}
+
+struct Row(usize);
+
+make_const_arr!(ARR47, [u8; 3], |Row(r)| r as u8 * 3);
+
+#[test]
+fn wrapped_index_test() {
+    assert_eq!(ARR47, [0, 3, 6]);
+}
+
+const ROWS: usize = 3;
+
+const fn triple(n: usize) -> u16 {
+    n as u16 * 3
+}
+
+make_const_arr!(ARR48, 4, u16, |i| i as u16 * 3);
+make_const_arr!(ARR49, [u16; 4], |i| i as u16 * 3);
+make_const_arr!(ARR50, ROWS, u16, triple);
+make_const_arr!(ARR51, [u16; ROWS], triple);
+make_const_arr!(ARR52, (ROWS * 2), u16; 9);
+make_const_arr!(ARR53, [u16; ROWS * 2]; 9);
+make_const_arr!(ARR54, 0, u16, |i| 1 / i as u16);
+make_const_arr!(pub ARR55, 2, &str, |i| ["a", "b"][i]);
+
+#[test]
+fn size_type_order_test() {
+    assert_eq!(ARR48, ARR49);
+    assert_eq!(ARR50, ARR51);
+    assert_eq!(ARR52, ARR53);
+    assert_eq!(ARR54, []);
+    assert_eq!(ARR55, ["a", "b"]);
+}
+
+struct Table<const N: usize>;
+
+const fn step(n: usize) -> u8 {
+    n as u8 + 10
+}
+
+impl<const N: usize> Table<N> {
+    make_const_arr!(Self::DOUBLED, [u8; N], |i| i as u8 * 2);
+    make_const_arr!(pub Self::STEPS, [u8; N], step);
+    make_const_arr!(Self::ONES, [u8; N]; 1);
+    make_const_arr!(
+        /// Associated const can also be declared without `Self::`
+        LAST,
+        [u8; N],
+        |i| (i as u8).wrapping_sub(1)
+    );
+}
+
+#[test]
+fn associated_const_test() {
+    assert_eq!(Table::<4>::DOUBLED, [0, 2, 4, 6]);
+    assert_eq!(Table::<2>::STEPS, [10, 11]);
+    assert_eq!(Table::<3>::ONES, [1, 1, 1]);
+    assert_eq!(Table::<0>::DOUBLED, []);
+    assert_eq!(Table::<2>::LAST, [255, 0]);
+}
+
+make_const_arr!(ARR56, [i32; 3], || 7);
+
+#[test]
+fn no_parameters_closure_test() {
+    assert_eq!(ARR56, [7, 7, 7]);
+}
+
+enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    const COUNT: usize = 4;
+}
+
+make_const_arr!(ARR57, [char; Dir::COUNT], |i| ['N', 'E', 'S', 'W'][i]);
+make_const_arr!(ARR58, Dir::COUNT, u8, |i| i as u8);
+
+#[test]
+fn associated_const_size_test() {
+    assert_eq!(ARR57[Dir::North as usize], 'N');
+    assert_eq!(ARR57[Dir::East as usize], 'E');
+    assert_eq!(ARR57[Dir::South as usize], 'S');
+    assert_eq!(ARR57[Dir::West as usize], 'W');
+    assert_eq!(ARR58, [0, 1, 2, 3]);
+}
+
+make_const_arr!(ARR59, [u32; 3], seed = 0, |i| 60 / i as u32);
+
+#[test]
+fn seed_test() {
+    assert_eq!(ARR59, [0, 60, 30]);
+}
+
+make_const_arr!(ARR60, [u8; 3], fn(i) { i as u8 + 1 });
+
+#[test]
+fn fn_sugar_test() {
+    assert_eq!(ARR60, [1, 2, 3]);
+}
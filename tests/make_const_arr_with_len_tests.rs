@@ -0,0 +1,57 @@
+use const_array_init::make_const_arr_with_len;
+
+make_const_arr_with_len!(ARR1, ARR1_LEN, [u8; 256], |i| i as u8);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR1_LEN, 256);
+    assert_eq!(ARR1.len(), ARR1_LEN);
+    assert_eq!(ARR1[ARR1_LEN - 1], 255);
+}
+
+const fn div_2(n: usize) -> i32 {
+    n as i32 / 2
+}
+
+const N: usize = 2;
+
+make_const_arr_with_len!(ARR2, ARR2_LEN, [i32; N * 2], div_2);
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR2_LEN, 4);
+    assert_eq!(ARR2, [0, 0, 1, 1]);
+}
+
+make_const_arr_with_len!(ARR3, ARR3_LEN, [u8; 3]; 7);
+
+#[test]
+fn fill_test() {
+    assert_eq!(ARR3_LEN, 3);
+    assert_eq!(ARR3, [7, 7, 7]);
+}
+
+mod tables {
+    use const_array_init::make_const_arr_with_len;
+
+    make_const_arr_with_len!(
+        /// Empty table
+        pub EMPTY, EMPTY_LEN, [u32; 0], |i| 100 / i as u32
+    );
+}
+
+#[test]
+fn visibility_test() {
+    assert_eq!(tables::EMPTY_LEN, 0);
+    assert_eq!(tables::EMPTY, []);
+}
+
+// Length is gated together with array, otherwise it would be defined twice
+make_const_arr_with_len!(#[cfg(not(test))] ARR4, ARR4_LEN, [u8; 2]; 1);
+make_const_arr_with_len!(#[cfg(test)] ARR4, ARR4_LEN, [u8; 3]; 2);
+
+#[test]
+fn cfg_test() {
+    assert_eq!(ARR4_LEN, 3);
+    assert_eq!(ARR4, [2, 2, 2]);
+}
@@ -0,0 +1,48 @@
+use const_array_init::make_const_arrs;
+
+make_const_arrs!([(S16, 16), (S32, 32), (S64, 64)], [u8; _], |i| i as u8);
+
+#[test]
+fn three_sizes_test() {
+    assert_eq!(S16.len(), 16);
+    assert_eq!(S32.len(), 32);
+    assert_eq!(S64.len(), 64);
+    assert_eq!(S16[15], 15);
+    assert_eq!(S32[31], 31);
+    assert_eq!(S64[63], 63);
+    assert_eq!(S16, S64.split_at(16).0);
+}
+
+const fn square(n: usize) -> u32 {
+    (n * n) as u32
+}
+
+const N: usize = 3;
+
+make_const_arrs!(pub [(SQ_N, N), (SQ_2N, N * 2),], [u32; _], square);
+
+#[test]
+fn function_test() {
+    assert_eq!(SQ_N, [0, 1, 4]);
+    assert_eq!(SQ_2N, [0, 1, 4, 9, 16, 25]);
+}
+
+make_const_arrs!(#[allow(dead_code)] [(FILL1, 1), (FILL2, 2)], [char; _]; 'x');
+
+#[test]
+fn value_test() {
+    assert_eq!(FILL1, ['x']);
+    assert_eq!(FILL2, ['x', 'x']);
+}
+
+struct Tables;
+
+impl Tables {
+    make_const_arrs!(pub [(SMALL, 2), (BIG, 4)], [i16; _], |i| -(i as i16));
+}
+
+#[test]
+fn associated_test() {
+    assert_eq!(Tables::SMALL, [0, -1]);
+    assert_eq!(Tables::BIG, [0, -1, -2, -3]);
+}
@@ -0,0 +1,42 @@
+use const_array_init::make_const_table;
+
+#[repr(transparent)]
+struct Table([u32; 4]);
+
+make_const_table!(TABLE1, Table, [u32; 4], |i| i as u32 * 2);
+
+#[test]
+fn closure_test() {
+    assert_eq!(TABLE1.0, [0, 2, 4, 6]);
+    assert_eq!(TABLE1.0[3], 6);
+}
+
+const fn square(n: usize) -> u32 { (n * n) as u32 }
+
+make_const_table!(TABLE2, Table, [u32; 4], square);
+
+#[test]
+fn function_test() {
+    assert_eq!(TABLE2.0, [0, 1, 4, 9]);
+}
+
+make_const_table!(TABLE3, Table, [u32; 4]; 7);
+
+#[test]
+fn fill_test() {
+    assert_eq!(TABLE3.0, [7, 7, 7, 7]);
+}
+
+mod tables {
+    pub struct Bytes(pub [u8; 3]);
+}
+
+make_const_table!(
+    #[allow(dead_code)]
+    pub(crate) TABLE4, tables::Bytes, [u8; 3], |i| i as u8 + 1
+);
+
+#[test]
+fn path_wrapper_test() {
+    assert_eq!(TABLE4.0, [1, 2, 3]);
+}
@@ -0,0 +1,74 @@
+use const_array_init::make_static_arr;
+
+make_static_arr!(ARR1, [i32; 3], div_2);
+
+const fn div_2(n: usize) -> i32 { n as i32 / 2 }
+
+#[test]
+fn function_test() {
+    assert_eq!(ARR1, [0, 0, 1]);
+}
+
+make_static_arr!(ARR2, [i32; 3], |i| i as i32 / 2);
+
+#[test]
+fn closure_test() {
+    assert_eq!(ARR2, [0, 0, 1]);
+}
+
+make_static_arr!(ARR3, [i32; 3], |_| 4 / 2);
+
+#[test]
+fn easy_closure_test() {
+    assert_eq!(ARR3, [2, 2, 2]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct User {
+    id: u32,
+}
+
+make_static_arr!(ARR4, [User; 3], |i| User { id: i as u32 });
+
+#[test]
+fn super_advanced_closure_test() {
+    assert_eq!(ARR4, [User { id: 0 }, User { id: 1 }, User { id: 2 },]);
+}
+
+make_static_arr!(TABLE, [u8; 65536], |i| (i % 251) as u8);
+
+fn lookup(n: u16) -> u8 {
+    TABLE[n as usize]
+}
+
+#[test]
+fn big_table_from_function_test() {
+    assert_eq!(lookup(0), 0);
+    assert_eq!(lookup(251), 0);
+    assert_eq!(lookup(65535), (65535 % 251) as u8);
+}
+
+make_static_arr!(ARR5, [i32; 3], |i| i as i32 / 2,);
+
+#[test]
+fn trailing_comma_test() {
+    assert_eq!(ARR5, [0, 0, 1]);
+}
+
+make_static_arr!(ARR6, [u8; 16]; 0xFF);
+
+#[test]
+fn fill_test() {
+    assert_eq!(ARR6, [0xFF; 16]);
+}
+
+const fn invalid_at_0(n: usize) -> i32 {
+    100 / n as i32
+}
+
+make_static_arr!(ARR7, [i32; 0], invalid_at_0);
+
+#[test]
+fn zero_length_test() {
+    assert_eq!(ARR7, []);
+}